@@ -0,0 +1,69 @@
+use std::collections::BTreeSet;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const EMBEDDED_DEX: &str = "data/embedded_dex.json";
+
+const SECTIONS: [(&str, &str, &str); 4] = [
+    ("species", "species", "crate::species::SpeciesId"),
+    ("moves", "moves", "crate::moves::MoveId"),
+    ("abilities", "abilities", "crate::monster::AbilityId"),
+    ("items", "items", "crate::item::ItemId"),
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", EMBEDDED_DEX);
+    if env::var_os("CARGO_FEATURE_EMBEDDED_DEX").is_none() {
+        return;
+    }
+
+    let text = fs::read_to_string(EMBEDDED_DEX).expect("embedded dex data should be readable");
+    let data: serde_json::Value =
+        serde_json::from_str(&text).expect("embedded dex data should be valid JSON");
+
+    let mut identifiers = String::new();
+    for (section, module, id_type) in SECTIONS.iter() {
+        writeln!(identifiers, "pub mod {} {{", module).unwrap();
+        let mut names = BTreeSet::new();
+        for entry in data[section].as_array().into_iter().flatten() {
+            let name = entry["name"]
+                .as_str()
+                .expect("dex entries should have a name");
+            let id = entry["id"].as_u64().expect("dex entries should have an ID");
+            let constant = constant_name(name);
+            if !names.insert(constant.clone()) {
+                panic!("{} {} would be defined more than once", section, constant);
+            }
+            writeln!(
+                identifiers,
+                "    pub const {}: {} = {}::new({});",
+                constant, id_type, id_type, id
+            )
+            .unwrap();
+        }
+        writeln!(identifiers, "}}").unwrap();
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("embedded_dex_ids.rs"), identifiers).unwrap();
+}
+
+fn constant_name(name: &str) -> String {
+    let mut constant = String::new();
+    for character in name.chars() {
+        if character.is_ascii_alphanumeric() {
+            constant.push(character.to_ascii_uppercase());
+        } else if !constant.ends_with('_') {
+            constant.push('_');
+        }
+    }
+    let constant = constant.trim_matches('_').to_string();
+    if constant.starts_with(|character: char| character.is_ascii_digit()) {
+        format!("_{}", constant)
+    } else {
+        constant
+    }
+}
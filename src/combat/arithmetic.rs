@@ -0,0 +1,83 @@
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+use crate::combat::DamageMultiplier;
+use crate::Fraction;
+
+pub(crate) const FIXED_POINT_ONE: u64 = 4096;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum ArithmeticMode {
+    #[default]
+    Decimal,
+    FixedPoint,
+}
+
+impl ArithmeticMode {
+    pub fn fraction_of(&self, fraction: &Fraction, value: Decimal) -> Decimal {
+        let amount = fraction.of(value);
+        match self {
+            ArithmeticMode::Decimal => amount,
+            ArithmeticMode::FixedPoint if value > 0.into() => amount.floor().max(1.into()),
+            ArithmeticMode::FixedPoint => amount.floor(),
+        }
+    }
+}
+
+pub(crate) fn to_fixed_point(multiplier: &DamageMultiplier) -> u64 {
+    (multiplier.value() * Decimal::from(FIXED_POINT_ONE))
+        .floor()
+        .to_u64()
+        .unwrap_or(0)
+}
+
+pub(crate) fn chain_modifiers(modifiers: &[u64]) -> u64 {
+    modifiers.iter().fold(FIXED_POINT_ONE, |chained, modifier| {
+        (chained * modifier + FIXED_POINT_ONE / 2) / FIXED_POINT_ONE
+    })
+}
+
+pub(crate) fn apply_modifier(value: u64, modifier: u64) -> u64 {
+    (value * modifier + FIXED_POINT_ONE / 2 - 1) / FIXED_POINT_ONE
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn converts_multiplier_to_4096_based_fixed_point() {
+        assert_that(&to_fixed_point(&DamageMultiplier::new(Decimal::new(13, 1)))).is_equal_to(5324);
+        assert_that(&to_fixed_point(&DamageMultiplier::new(Decimal::new(15, 1)))).is_equal_to(6144);
+    }
+
+    #[test]
+    fn applying_modifier_rounds_half_down() {
+        assert_that(&apply_modifier(5, 6144)).is_equal_to(7);
+        assert_that(&apply_modifier(7, 6144)).is_equal_to(10);
+    }
+
+    #[test]
+    fn applying_modifier_rounds_above_half_up() {
+        assert_that(&apply_modifier(100, 5324)).is_equal_to(130);
+        assert_that(&apply_modifier(101, 5324)).is_equal_to(131);
+    }
+
+    #[test]
+    fn chains_modifiers_with_rounding() {
+        assert_that(&chain_modifiers(&[5324, 6144])).is_equal_to(7986);
+        assert_that(&chain_modifiers(&[])).is_equal_to(4096);
+    }
+
+    #[test]
+    fn fixed_point_fractions_are_floored_to_at_least_one() {
+        assert_that(&ArithmeticMode::FixedPoint.fraction_of(&Fraction::new(1, 16), 10.into()))
+            .is_equal_to(Decimal::from(1));
+        assert_that(&ArithmeticMode::FixedPoint.fraction_of(&Fraction::new(1, 3), 100.into()))
+            .is_equal_to(Decimal::from(33));
+        assert_that(&ArithmeticMode::Decimal.fraction_of(&Fraction::new(1, 4), 10.into()))
+            .is_equal_to(Decimal::new(25, 1));
+    }
+}
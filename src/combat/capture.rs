@@ -0,0 +1,309 @@
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+use crate::combat::RandomNumberGenerator;
+use crate::monster::{Monster, StatusCondition};
+
+const SHAKE_CHECKS: u8 = 4;
+const MAX_VISIBLE_SHAKES: u8 = 3;
+const GUARANTEED_CAPTURE_RATE: u32 = 255;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Ball {
+    Standard,
+    Great,
+    Ultra,
+    Master,
+    Custom(Decimal),
+}
+
+impl Ball {
+    pub fn modifier(&self) -> Decimal {
+        match self {
+            Ball::Standard | Ball::Master => 1.into(),
+            Ball::Great => Decimal::new(15, 1),
+            Ball::Ultra => 2.into(),
+            Ball::Custom(modifier) => *modifier,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct CaptureOutcome {
+    shakes: u8,
+    captured: bool,
+    critical: bool,
+}
+
+impl CaptureOutcome {
+    pub fn shakes(&self) -> u8 {
+        self.shakes
+    }
+
+    pub fn is_captured(&self) -> bool {
+        self.captured
+    }
+
+    pub fn is_critical(&self) -> bool {
+        self.critical
+    }
+}
+
+pub struct CaptureCalculator<RNG: RandomNumberGenerator> {
+    random_number_generator: RNG,
+}
+
+impl<RNG: RandomNumberGenerator> CaptureCalculator<RNG> {
+    pub fn new(random_number_generator: RNG) -> Self {
+        CaptureCalculator {
+            random_number_generator,
+        }
+    }
+
+    fn status_modifier(target: &Monster) -> Decimal {
+        match target.status() {
+            Some(StatusCondition::Sleep) | Some(StatusCondition::Freeze) => Decimal::new(25, 1),
+            Some(_) => Decimal::new(15, 1),
+            None => 1.into(),
+        }
+    }
+
+    fn critical_capture_multiplier(caught_species_count: u16) -> Decimal {
+        match caught_species_count {
+            0..=30 => 0.into(),
+            31..=150 => Decimal::new(5, 1),
+            151..=300 => 1.into(),
+            301..=450 => Decimal::new(15, 1),
+            451..=600 => 2.into(),
+            _ => Decimal::new(25, 1),
+        }
+    }
+
+    pub fn capture_rate(&self, target: &Monster, ball: Ball) -> u32 {
+        if ball == Ball::Master {
+            return GUARANTEED_CAPTURE_RATE;
+        }
+
+        let max_health = target.max_health().value();
+        if max_health <= 0.into() {
+            return GUARANTEED_CAPTURE_RATE;
+        }
+        let catch_rate =
+            Decimal::from(target.species().map_or(255, |species| species.catch_rate()));
+        let health_factor = ((Decimal::from(3) * max_health
+            - Decimal::from(2) * target.health().value())
+            * catch_rate
+            * ball.modifier()
+            / (Decimal::from(3) * max_health))
+            .floor();
+
+        (health_factor * Self::status_modifier(target))
+            .floor()
+            .to_u32()
+            .unwrap_or(0)
+            .max(1)
+    }
+
+    pub fn shake_probability(capture_rate: u32) -> u32 {
+        if capture_rate >= GUARANTEED_CAPTURE_RATE {
+            return 65536;
+        }
+        let inner = integer_square_root(16_711_680 / capture_rate);
+        1_048_560 / integer_square_root(inner)
+    }
+
+    pub fn attempt_capture(
+        &self,
+        target: &Monster,
+        ball: Ball,
+        caught_species_count: u16,
+    ) -> Result<CaptureOutcome, CaptureError> {
+        if target.is_fainted() {
+            return Err(CaptureError::TargetIsFainted {
+                target: target.name().to_string(),
+            });
+        }
+
+        let capture_rate = self.capture_rate(target, ball);
+        if capture_rate >= GUARANTEED_CAPTURE_RATE {
+            return Ok(CaptureOutcome {
+                shakes: MAX_VISIBLE_SHAKES,
+                captured: true,
+                critical: false,
+            });
+        }
+
+        let critical_rate = (Decimal::from(capture_rate.min(255))
+            * Self::critical_capture_multiplier(caught_species_count)
+            / Decimal::from(6))
+        .floor()
+        .to_u32()
+        .unwrap_or(0);
+        let critical = self.random_number_generator.next_below(256) < critical_rate;
+
+        let required_checks = if critical { 1 } else { SHAKE_CHECKS };
+        let shake_probability = Self::shake_probability(capture_rate);
+        let passed_checks = (0..required_checks)
+            .take_while(|_| self.random_number_generator.next_below(65536) < shake_probability)
+            .count() as u8;
+
+        Ok(CaptureOutcome {
+            shakes: passed_checks.min(MAX_VISIBLE_SHAKES),
+            captured: passed_checks == required_checks,
+            critical,
+        })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum CaptureError {
+    #[error("{target} has fainted and cannot be captured")]
+    TargetIsFainted { target: String },
+}
+
+fn integer_square_root(value: u32) -> u32 {
+    let value = u64::from(value);
+    let mut root = (value as f64).sqrt() as u64;
+    while root * root > value {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= value {
+        root += 1;
+    }
+    root as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+
+    use crate::combat::MockRandomNumberGenerator;
+    use crate::monster::{Damage, Level, MonsterBuilder, MonsterType, Stats};
+    use crate::species::Species;
+    use crate::Element;
+
+    use super::*;
+
+    fn target(health_lost: u32) -> Monster {
+        let species = Rc::new(
+            Species::new(
+                "Abra",
+                MonsterType::new(Element::Psychic, None),
+                Stats::new(25, 20, 15, 105, 55, 90),
+                Vec::new(),
+                Vec::new(),
+            )
+            .with_catch_rate(200),
+        );
+        let mut monster = MonsterBuilder::new(species, Level::new(50))
+            .build()
+            .unwrap();
+        monster.receive_damage(Damage::new(health_lost.into()));
+        monster
+    }
+
+    fn rng(critical_roll: u32, shake_roll: u32) -> MockRandomNumberGenerator {
+        let mut rng = MockRandomNumberGenerator::default();
+        rng.expect_next_below()
+            .with(eq(256))
+            .returning(move |_| critical_roll);
+        rng.expect_next_below()
+            .with(eq(65536))
+            .returning(move |_| shake_roll);
+        rng
+    }
+
+    #[test]
+    fn capture_rate_increases_as_health_decreases() {
+        let calculator = CaptureCalculator::new(rng(255, 0));
+
+        assert_that(&calculator.capture_rate(&target(0), Ball::Standard)).is_equal_to(66);
+        assert_that(&calculator.capture_rate(&target(84), Ball::Standard)).is_equal_to(198);
+    }
+
+    #[test]
+    fn capture_rate_includes_ball_and_status_modifiers() {
+        let mut sleeping_target = target(0);
+        sleeping_target.inflict_status(StatusCondition::Sleep);
+
+        assert_that(
+            &CaptureCalculator::new(rng(255, 0)).capture_rate(&sleeping_target, Ball::Great),
+        )
+        .is_equal_to(250);
+    }
+
+    #[test]
+    fn calculates_shake_probability() {
+        assert_that(&CaptureCalculator::<MockRandomNumberGenerator>::shake_probability(66))
+            .is_equal_to(47661);
+    }
+
+    #[test]
+    fn captures_when_all_shake_checks_pass() {
+        let outcome = CaptureCalculator::new(rng(255, 47660))
+            .attempt_capture(&target(0), Ball::Standard, 0)
+            .unwrap();
+
+        assert_that(&outcome.is_captured()).is_true();
+        assert_that(&outcome.shakes()).is_equal_to(3);
+    }
+
+    #[test]
+    fn escapes_when_first_shake_check_fails() {
+        let outcome = CaptureCalculator::new(rng(255, 47661))
+            .attempt_capture(&target(0), Ball::Standard, 0)
+            .unwrap();
+
+        assert_that(&outcome.is_captured()).is_false();
+        assert_that(&outcome.shakes()).is_equal_to(0);
+    }
+
+    #[test]
+    fn master_ball_always_captures() {
+        let outcome = CaptureCalculator::new(MockRandomNumberGenerator::default())
+            .attempt_capture(&target(0), Ball::Master, 0)
+            .unwrap();
+
+        assert_that(&outcome.is_captured()).is_true();
+    }
+
+    #[test]
+    fn critical_capture_needs_single_shake_check() {
+        let mut rng = MockRandomNumberGenerator::default();
+        rng.expect_next_below()
+            .with(eq(256))
+            .times(1)
+            .returning(|_| 0);
+        rng.expect_next_below()
+            .with(eq(65536))
+            .times(1)
+            .returning(|_| 0);
+
+        let outcome = CaptureCalculator::new(rng)
+            .attempt_capture(&target(0), Ball::Standard, 700)
+            .unwrap();
+
+        assert_that(&outcome.is_critical()).is_true();
+        assert_that(&outcome.is_captured()).is_true();
+        assert_that(&outcome.shakes()).is_equal_to(1);
+    }
+
+    #[test]
+    fn refuses_to_capture_fainted_target() {
+        let fainted_target = target(1000);
+
+        assert_that(
+            &CaptureCalculator::new(MockRandomNumberGenerator::default()).attempt_capture(
+                &fainted_target,
+                Ball::Master,
+                0,
+            ),
+        )
+        .is_equal_to(Err(CaptureError::TargetIsFainted {
+            target: "Abra".to_string(),
+        }));
+    }
+}
@@ -0,0 +1,5 @@
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum CombatEvent {
+    AttackerFainted,
+    DefenderFainted,
+}
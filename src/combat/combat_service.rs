@@ -1,51 +1,680 @@
-use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
 
-use crate::combat::{DamageMultiplier, TypeEffectivenessCalculator};
-use crate::monster::{Attack, Damage, Monster};
+use crate::combat::{
+    ArithmeticMode, BattleKind, CombatEvent, DamageFormula, DamageModifierContext,
+    DamageMultiplier, DamagePipeline, Field, MoveOutcome, PrimitiveDamageMultiplier,
+    RandomNumberGenerator, StabCalculator, StabCalculatorImpl, TypeEffectivenessCalculator,
+};
+use crate::item::{DamageContext, HitContext, HolderRole};
+use crate::monster::{
+    Attack, AttackCategory, BattleStat, Damage, Health, Monster, MoveSlot, Stat, StatusCondition,
+    MAX_SLEEP_TURNS,
+};
+use crate::moves::{Chance, EffectTarget, HitCount, MoveDamage, MoveDefinition, MoveEffect};
+use crate::ruleset::{Clause, Ruleset, RulesetViolation};
+
+const THAW_CHANCE_PERCENT: u8 = 20;
 
 #[derive(Default)]
-pub struct CombatService<TEC: TypeEffectivenessCalculator> {
+pub struct CombatService<
+    TEC: TypeEffectivenessCalculator,
+    RNG: RandomNumberGenerator,
+    STAB: StabCalculator = StabCalculatorImpl,
+> {
     type_effectiveness_calculator: TEC,
+    random_number_generator: RNG,
+    stab_calculator: STAB,
+    damage_pipeline: DamagePipeline,
+    field: Field,
+    ruleset: Option<Ruleset>,
+    arithmetic_mode: ArithmeticMode,
+    damage_formula: DamageFormula,
 }
 
-impl<TEC: TypeEffectivenessCalculator> CombatService<TEC> {
-    pub fn new(type_effectiveness_calculator: TEC) -> Self {
+impl<TEC: TypeEffectivenessCalculator, RNG: RandomNumberGenerator> CombatService<TEC, RNG> {
+    pub fn new(type_effectiveness_calculator: TEC, random_number_generator: RNG) -> Self {
         CombatService {
             type_effectiveness_calculator,
+            random_number_generator,
+            stab_calculator: StabCalculatorImpl::new(),
+            damage_pipeline: DamagePipeline::standard(),
+            field: Field::default(),
+            ruleset: None,
+            arithmetic_mode: ArithmeticMode::default(),
+            damage_formula: DamageFormula::default(),
         }
     }
+}
 
-    fn stab_multiplier(attacker: &Monster, attack: &Attack) -> DamageMultiplier {
-        if attacker.monster_type().primary_element() == attack.element() {
-            return DamageMultiplier::new(Decimal::new(15, 1));
+impl<TEC: TypeEffectivenessCalculator, RNG: RandomNumberGenerator, STAB: StabCalculator>
+    CombatService<TEC, RNG, STAB>
+{
+    pub fn with_stab_calculator<S: StabCalculator>(
+        self,
+        stab_calculator: S,
+    ) -> CombatService<TEC, RNG, S> {
+        CombatService {
+            type_effectiveness_calculator: self.type_effectiveness_calculator,
+            random_number_generator: self.random_number_generator,
+            stab_calculator,
+            damage_pipeline: self.damage_pipeline,
+            field: self.field,
+            ruleset: self.ruleset,
+            arithmetic_mode: self.arithmetic_mode,
+            damage_formula: self.damage_formula,
         }
-        DamageMultiplier::new(1.into())
+    }
+
+    pub fn with_damage_pipeline(mut self, damage_pipeline: DamagePipeline) -> Self {
+        self.damage_pipeline = damage_pipeline;
+        self
+    }
+
+    pub fn damage_pipeline_mut(&mut self) -> &mut DamagePipeline {
+        &mut self.damage_pipeline
+    }
+
+    pub fn with_field(mut self, field: Field) -> Self {
+        self.field = field;
+        self
+    }
+
+    /// Enforces the ruleset's move bans and the OHKO, Evasion and Sleep clauses during
+    /// battle. Team-composition clauses are checked by `TeamValidator` instead.
+    pub fn with_ruleset(mut self, ruleset: Ruleset) -> Self {
+        self.ruleset = Some(ruleset);
+        self
+    }
+
+    pub fn ruleset(&self) -> Option<&Ruleset> {
+        self.ruleset.as_ref()
+    }
+
+    pub fn field(&self) -> &Field {
+        &self.field
+    }
+
+    pub fn field_mut(&mut self) -> &mut Field {
+        &mut self.field
+    }
+
+    pub fn with_arithmetic_mode(mut self, arithmetic_mode: ArithmeticMode) -> Self {
+        self.arithmetic_mode = arithmetic_mode;
+        self
+    }
+
+    pub fn arithmetic_mode(&self) -> ArithmeticMode {
+        self.arithmetic_mode
+    }
+
+    pub fn with_damage_formula(mut self, damage_formula: DamageFormula) -> Self {
+        self.damage_formula = damage_formula;
+        self
+    }
+
+    pub fn damage_formula(&self) -> DamageFormula {
+        self.damage_formula
+    }
+
+    fn stat_stage_multiplier(
+        attacker: &Monster,
+        attack: &Attack,
+        defender: &Monster,
+    ) -> DamageMultiplier {
+        let (offensive_stat, defensive_stat) = match attack.category() {
+            AttackCategory::Physical => (BattleStat::Attack, BattleStat::Defense),
+            AttackCategory::Special => (BattleStat::SpecialAttack, BattleStat::SpecialDefense),
+            AttackCategory::Status => return PrimitiveDamageMultiplier::Single.into(),
+        };
+        DamageMultiplier::new(
+            attacker.stat_stages().multiplier(offensive_stat)
+                / defender.stat_stages().multiplier(defensive_stat),
+        )
     }
 
     pub fn perform_attack(
         &self,
-        attacker: &Monster,
+        attacker: &mut Monster,
         attack: Attack,
         defender: &mut Monster,
     ) -> Result<(), CombatError> {
-        if self.is_defeated(defender) {
-            return Err(CombatError::DefenderIsAlreadyDefeated);
+        Self::validate_combatants(attacker, defender)?;
+        let slot = attacker
+            .moveset()
+            .slots()
+            .iter()
+            .position(|move_slot| move_slot.move_definition().attack() == attack)
+            .ok_or_else(|| CombatError::AttackNotInMoveset {
+                attacker: attacker.name().to_string(),
+                attack: attack.clone(),
+            })?;
+        self.begin_move(attacker, slot)?;
+
+        let stab_multiplier = self.stab_calculator.calculate(attacker, &attack);
+        let type_effectiveness_multiplier = self.type_effectiveness(&attack, defender);
+        let damage = self.attack_damage(
+            attacker,
+            &attack,
+            defender,
+            stab_multiplier,
+            type_effectiveness_multiplier,
+        );
+        self.land_hit(
+            attacker,
+            &attack,
+            defender,
+            &type_effectiveness_multiplier,
+            damage,
+        );
+        Ok(())
+    }
+
+    pub fn perform_move(
+        &self,
+        attacker: &mut Monster,
+        move_definition: &MoveDefinition,
+        defender: &mut Monster,
+        defender_teammates: &[Monster],
+    ) -> Result<MoveOutcome, CombatError> {
+        let attack = move_definition.attack();
+        Self::validate_combatants(attacker, defender)?;
+        let slot = attacker
+            .moveset()
+            .slots()
+            .iter()
+            .position(|move_slot| move_slot.move_definition().name() == move_definition.name())
+            .ok_or_else(|| CombatError::MoveNotInMoveset {
+                attacker: attacker.name().to_string(),
+                move_name: move_definition.name().to_string(),
+            })?;
+        self.begin_move(attacker, slot)?;
+
+        let stab_multiplier = self.stab_calculator.calculate(attacker, &attack);
+        let type_effectiveness_multiplier = self.type_effectiveness(&attack, defender);
+        let sleep_clause_applies = self.sleep_clause_applies(defender_teammates);
+        Ok(self.execute_move(
+            attacker,
+            move_definition,
+            defender,
+            stab_multiplier,
+            type_effectiveness_multiplier,
+            sleep_clause_applies,
+        ))
+    }
+
+    pub fn use_move(
+        &self,
+        attacker: &mut Monster,
+        slot: usize,
+        defender: &mut Monster,
+        defender_teammates: &[Monster],
+    ) -> Result<MoveOutcome, CombatError> {
+        if attacker.moveset().is_empty() || attacker.moveset().is_exhausted() {
+            return self.perform_struggle(attacker, defender);
         }
 
-        let stab_multiplier = Self::stab_multiplier(attacker, &attack);
-        let type_effectiveness_multiplier = self
-            .type_effectiveness_calculator
-            .calculate(attack.element(), defender.monster_type());
-        let combined_damage_multiplier =
-            stab_multiplier.combined_with(type_effectiveness_multiplier);
+        let move_slot =
+            attacker
+                .moveset()
+                .slot(slot)
+                .ok_or_else(|| CombatError::MoveSlotIsEmpty {
+                    attacker: attacker.name().to_string(),
+                    slot,
+                })?;
+        let move_definition = move_slot.move_definition().clone();
+        self.perform_move(attacker, &move_definition, defender, defender_teammates)
+    }
 
-        let damage = self.damage_from_attack(&attack, combined_damage_multiplier);
-        defender.receive_damage(damage);
+    pub fn attempt_flee(
+        &self,
+        runner: &Monster,
+        opponent: &Monster,
+        attempts: u8,
+        battle_kind: BattleKind,
+    ) -> Result<bool, CombatError> {
+        if battle_kind == BattleKind::Trainer {
+            return Err(CombatError::CannotFleeTrainerBattle);
+        }
+
+        let runner_speed = runner.effective_speed();
+        let opponent_speed = opponent.effective_speed();
+        let scaled_opponent_speed = (opponent_speed / 4) % 256;
+        if runner_speed >= opponent_speed || scaled_opponent_speed == 0 {
+            return Ok(true);
+        }
+
+        let escape_odds = runner_speed * 32 / scaled_opponent_speed + 30 * u32::from(attempts);
+        if escape_odds > 255 {
+            return Ok(true);
+        }
+        Ok(self.random_number_generator.next_below(256) < escape_odds)
+    }
+
+    pub fn switch(&self, outgoing: &mut Monster, incoming: &Monster) -> Result<(), CombatError> {
+        if incoming.is_fainted() {
+            return Err(CombatError::FaintedMonsterCannotSwitchIn {
+                monster: incoming.name().to_string(),
+            });
+        }
+
+        outgoing.switch_out();
+        Ok(())
+    }
+
+    pub fn perform_struggle(
+        &self,
+        attacker: &mut Monster,
+        defender: &mut Monster,
+    ) -> Result<MoveOutcome, CombatError> {
+        Self::validate_combatants(attacker, defender)?;
+        self.resolve_attacker_status(attacker)?;
+
+        Ok(self.execute_move(
+            attacker,
+            &MoveDefinition::struggle(),
+            defender,
+            PrimitiveDamageMultiplier::Single.into(),
+            PrimitiveDamageMultiplier::Single.into(),
+            false,
+        ))
+    }
+
+    fn execute_move(
+        &self,
+        attacker: &mut Monster,
+        move_definition: &MoveDefinition,
+        defender: &mut Monster,
+        stab_multiplier: DamageMultiplier,
+        type_effectiveness_multiplier: DamageMultiplier,
+        sleep_clause_applies: bool,
+    ) -> MoveOutcome {
+        let attack = move_definition.attack();
+        let mut outcome = MoveOutcome::default();
+        if move_definition.damage() != &MoveDamage::None {
+            if type_effectiveness_multiplier.value() == 0.into() {
+                return outcome;
+            }
+
+            let health_before = *defender.health();
+            for _ in 0..self.roll_hit_count(move_definition.hit_count()) {
+                if defender.is_fainted() {
+                    break;
+                }
+                let damage = match move_definition.damage() {
+                    MoveDamage::Power(_) => self.attack_damage(
+                        attacker,
+                        &attack,
+                        defender,
+                        stab_multiplier,
+                        type_effectiveness_multiplier,
+                    ),
+                    fixed_damage => Self::fixed_move_damage(attacker, fixed_damage, defender),
+                };
+                self.land_hit(
+                    attacker,
+                    &attack,
+                    defender,
+                    &type_effectiveness_multiplier,
+                    damage,
+                );
+                outcome.record_hit();
+            }
+            outcome.record_damage_dealt(Damage::new(
+                health_before.value() - defender.health().value(),
+            ));
+        }
+
+        for effect in move_definition.effects() {
+            if sleep_clause_applies
+                && matches!(
+                    effect,
+                    MoveEffect::InflictStatus {
+                        status: StatusCondition::Sleep,
+                        ..
+                    }
+                )
+            {
+                continue;
+            }
+            if self.apply_effect(effect, attacker, defender, outcome.damage_dealt()) {
+                outcome.record_applied_effect(*effect);
+            }
+        }
+
+        if defender.is_fainted() {
+            outcome.record_event(CombatEvent::DefenderFainted);
+        }
+        if attacker.is_fainted() {
+            outcome.record_event(CombatEvent::AttackerFainted);
+        }
+        outcome
+    }
+
+    fn validate_combatants(attacker: &Monster, defender: &Monster) -> Result<(), CombatError> {
+        if attacker.is_fainted() {
+            return Err(CombatError::AttackerIsFainted {
+                attacker: attacker.name().to_string(),
+            });
+        }
+        if defender.is_fainted() {
+            return Err(CombatError::DefenderIsAlreadyDefeated {
+                defender: defender.name().to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    fn validate_move(&self, attacker: &Monster, move_slot: &MoveSlot) -> Result<(), CombatError> {
+        let move_definition = move_slot.move_definition();
+        if let Some(item) = attacker.held_item() {
+            if !item.permits_move(move_definition) {
+                return Err(CombatError::MoveNotPermittedByHeldItem {
+                    attacker: attacker.name().to_string(),
+                    move_name: move_definition.name().to_string(),
+                    item: item.name().to_string(),
+                });
+            }
+        }
+        Self::validate_move_slot(attacker, move_slot)?;
+        self.validate_ruleset(attacker, move_definition)
+    }
+
+    fn begin_move(&self, attacker: &mut Monster, slot: usize) -> Result<(), CombatError> {
+        let move_slot =
+            attacker
+                .moveset()
+                .slot(slot)
+                .ok_or_else(|| CombatError::MoveSlotIsEmpty {
+                    attacker: attacker.name().to_string(),
+                    slot,
+                })?;
+        let move_definition = move_slot.move_definition().clone();
+        self.validate_move(attacker, move_slot)?;
+        self.resolve_attacker_status(attacker)?;
+
+        if let Some(move_slot) = attacker.moveset_mut().slot_mut(slot) {
+            move_slot.deduct_pp();
+        }
+        attacker.trigger_held_item(|item, _| {
+            item.on_move_used(&move_definition);
+            Vec::new()
+        });
+        Ok(())
+    }
+
+    fn resolve_attacker_status(&self, attacker: &mut Monster) -> Result<(), CombatError> {
+        let attacker_name = attacker.name().to_string();
+        match attacker.status().copied() {
+            Some(StatusCondition::Sleep) if attacker.tick_sleep() => {
+                return Err(CombatError::AttackerIsAsleep {
+                    attacker: attacker_name,
+                })
+            }
+            Some(StatusCondition::Freeze) if self.roll(&Chance::percent(THAW_CHANCE_PERCENT)) => {
+                attacker.cure_status()
+            }
+            Some(StatusCondition::Freeze) => {
+                return Err(CombatError::AttackerIsFrozen {
+                    attacker: attacker_name,
+                })
+            }
+            _ => {}
+        }
+        if attacker.is_flinched() {
+            return Err(CombatError::AttackerIsFlinched {
+                attacker: attacker_name,
+            });
+        }
+        Ok(())
+    }
+
+    fn validate_ruleset(
+        &self,
+        attacker: &Monster,
+        move_definition: &MoveDefinition,
+    ) -> Result<(), CombatError> {
+        match &self.ruleset {
+            Some(ruleset) => ruleset.check_move(move_definition).map_err(|violation| {
+                CombatError::MoveForbiddenByRuleset {
+                    attacker: attacker.name().to_string(),
+                    violation,
+                }
+            }),
+            None => Ok(()),
+        }
+    }
+
+    fn sleep_clause_applies(&self, defender_teammates: &[Monster]) -> bool {
+        self.ruleset
+            .as_ref()
+            .is_some_and(|ruleset| ruleset.has_clause(Clause::Sleep))
+            && defender_teammates
+                .iter()
+                .any(|monster| monster.status() == Some(&StatusCondition::Sleep))
+    }
+
+    fn validate_move_slot(attacker: &Monster, move_slot: &MoveSlot) -> Result<(), CombatError> {
+        if move_slot.current_pp() == 0 {
+            return Err(CombatError::NoPowerPointsRemaining {
+                attacker: attacker.name().to_string(),
+                move_name: move_slot.move_definition().name().to_string(),
+            });
+        }
         Ok(())
     }
 
-    fn is_defeated(&self, monster: &Monster) -> bool {
-        monster.health().value() == 0.into()
+    fn type_effectiveness(&self, attack: &Attack, defender: &Monster) -> DamageMultiplier {
+        self.type_effectiveness_calculator
+            .calculate(attack.element(), defender.monster_type())
+    }
+
+    fn attack_damage(
+        &self,
+        attacker: &Monster,
+        attack: &Attack,
+        defender: &Monster,
+        stab_multiplier: DamageMultiplier,
+        type_effectiveness_multiplier: DamageMultiplier,
+    ) -> Damage {
+        if type_effectiveness_multiplier.value() == 0.into() {
+            return Damage::new(0.into());
+        }
+
+        let context = DamageModifierContext::new(
+            attacker,
+            defender,
+            attack,
+            &self.field,
+            stab_multiplier,
+            type_effectiveness_multiplier,
+        );
+        match self.damage_formula {
+            DamageFormula::Power => self.damage_pipeline.apply(
+                self.damage_from_attack(
+                    attack,
+                    Self::stat_stage_multiplier(attacker, attack, defender),
+                ),
+                &context,
+                self.arithmetic_mode,
+                None,
+            ),
+            DamageFormula::Standard => {
+                let damage = self.damage_pipeline.apply(
+                    Self::standard_base_damage(attacker, attack, defender),
+                    &context,
+                    self.arithmetic_mode,
+                    Some(85 + self.random_number_generator.next_below(16)),
+                );
+                Damage::new(damage.value().max(1.into()))
+            }
+        }
+    }
+
+    fn standard_base_damage(attacker: &Monster, attack: &Attack, defender: &Monster) -> Damage {
+        let (offense, defense) = match attack.category() {
+            AttackCategory::Physical => (
+                Self::staged_stat(attacker, Stat::Attack, BattleStat::Attack),
+                Self::staged_stat(defender, Stat::Defense, BattleStat::Defense),
+            ),
+            AttackCategory::Special => (
+                Self::staged_stat(attacker, Stat::SpecialAttack, BattleStat::SpecialAttack),
+                Self::staged_stat(defender, Stat::SpecialDefense, BattleStat::SpecialDefense),
+            ),
+            AttackCategory::Status => return Damage::new(0.into()),
+        };
+        let level = u64::from(attacker.level().value());
+        let power = attack.power().value().floor().to_u64().unwrap_or(0);
+
+        let base_damage = (2 * level / 5 + 2) * power * offense / defense.max(1) / 50 + 2;
+        Damage::new(base_damage.into())
+    }
+
+    fn staged_stat(monster: &Monster, stat: Stat, battle_stat: BattleStat) -> u64 {
+        let stage = i64::from(monster.stat_stages().stage(battle_stat));
+        let (numerator, denominator) = ((2 + stage).max(2), (2 - stage).max(2));
+        u64::from(monster.stats().get(stat)) * numerator as u64 / denominator as u64
+    }
+
+    fn fixed_move_damage(
+        attacker: &Monster,
+        move_damage: &MoveDamage,
+        defender: &Monster,
+    ) -> Damage {
+        match move_damage {
+            MoveDamage::Fixed(damage) => *damage,
+            MoveDamage::Level => Damage::new(attacker.level().value().into()),
+            MoveDamage::OneHitKnockOut if attacker.level() >= defender.level() => {
+                Damage::new(defender.health().value())
+            }
+            _ => Damage::new(0.into()),
+        }
+    }
+
+    fn land_hit(
+        &self,
+        attacker: &mut Monster,
+        attack: &Attack,
+        defender: &mut Monster,
+        type_effectiveness_multiplier: &DamageMultiplier,
+        damage: Damage,
+    ) {
+        defender.receive_damage(damage);
+
+        for (holder, role) in [
+            (defender, HolderRole::Defender),
+            (attacker, HolderRole::Attacker),
+        ] {
+            holder.trigger_held_item(|item, holder| {
+                item.on_hit(
+                    &HitContext::new(
+                        DamageContext::new(role, attack, type_effectiveness_multiplier),
+                        holder,
+                        &damage,
+                    )
+                    .with_arithmetic_mode(self.arithmetic_mode),
+                )
+            });
+        }
+    }
+
+    fn apply_effect(
+        &self,
+        effect: &MoveEffect,
+        attacker: &mut Monster,
+        defender: &mut Monster,
+        damage_dealt: &Damage,
+    ) -> bool {
+        let defender_is_defeated = defender.is_fainted();
+        match effect {
+            MoveEffect::InflictStatus {
+                status: StatusCondition::Sleep,
+                chance,
+            } => {
+                !defender_is_defeated
+                    && self.roll(chance)
+                    && defender.inflict_sleep(self.roll_sleep_turns())
+            }
+            MoveEffect::InflictStatus { status, chance } => {
+                !defender_is_defeated && self.roll(chance) && defender.inflict_status(*status)
+            }
+            MoveEffect::Flinch { chance } => {
+                !defender_is_defeated && self.roll(chance) && {
+                    defender.flinch();
+                    true
+                }
+            }
+            MoveEffect::StatChange {
+                target,
+                stat,
+                stages,
+                chance,
+            } => {
+                let monster = match target {
+                    EffectTarget::User => attacker,
+                    EffectTarget::Target if !defender_is_defeated => defender,
+                    EffectTarget::Target => return false,
+                };
+                self.roll(chance) && monster.change_stat_stage(*stat, *stages) != 0
+            }
+            MoveEffect::Recoil(fraction) => {
+                damage_dealt.value() > 0.into() && {
+                    attacker.lose_health(Damage::new(
+                        self.arithmetic_mode
+                            .fraction_of(fraction, damage_dealt.value()),
+                    ));
+                    true
+                }
+            }
+            MoveEffect::SelfDamage(fraction) => {
+                let amount = self
+                    .arithmetic_mode
+                    .fraction_of(fraction, attacker.max_health().value());
+                attacker.lose_health(Damage::new(amount));
+                true
+            }
+            MoveEffect::Drain(fraction) => {
+                damage_dealt.value() > 0.into() && {
+                    attacker.heal(Health::new(
+                        self.arithmetic_mode
+                            .fraction_of(fraction, damage_dealt.value()),
+                    ));
+                    true
+                }
+            }
+            MoveEffect::Heal(fraction) => {
+                let amount = self
+                    .arithmetic_mode
+                    .fraction_of(fraction, attacker.max_health().value());
+                attacker.heal(Health::new(amount));
+                true
+            }
+            MoveEffect::MultiHit(_) => false,
+        }
+    }
+
+    fn roll(&self, chance: &Chance) -> bool {
+        if chance.value() >= 100 {
+            return true;
+        }
+        self.random_number_generator.next_below(100) < u32::from(chance.value())
+    }
+
+    fn roll_sleep_turns(&self) -> u8 {
+        1 + self
+            .random_number_generator
+            .next_below(u32::from(MAX_SLEEP_TURNS)) as u8
+    }
+
+    fn roll_hit_count(&self, hit_count: HitCount) -> u8 {
+        match hit_count {
+            HitCount::Fixed(hits) => hits,
+            HitCount::TwoToFive => match self.random_number_generator.next_below(100) {
+                0..=34 => 2,
+                35..=69 => 3,
+                70..=84 => 4,
+                _ => 5,
+            },
+        }
     }
 
     fn damage_from_attack(&self, attack: &Attack, multiplier: DamageMultiplier) -> Damage {
@@ -55,19 +684,57 @@ impl<TEC: TypeEffectivenessCalculator> CombatService<TEC> {
 
 #[derive(Debug, thiserror::Error)]
 pub enum CombatError {
-    #[error("Defender is already defeated")]
-    DefenderIsAlreadyDefeated,
+    #[error("{attacker} has fainted and cannot attack")]
+    AttackerIsFainted { attacker: String },
+    #[error("{attacker} is asleep and cannot attack")]
+    AttackerIsAsleep { attacker: String },
+    #[error("{attacker} is frozen and cannot attack")]
+    AttackerIsFrozen { attacker: String },
+    #[error("{attacker} flinched and cannot attack")]
+    AttackerIsFlinched { attacker: String },
+    #[error("{defender} is already defeated")]
+    DefenderIsAlreadyDefeated { defender: String },
+    #[error("{attacker} cannot use {move_name} because its {item} does not permit it")]
+    MoveNotPermittedByHeldItem {
+        attacker: String,
+        move_name: String,
+        item: String,
+    },
+    #[error("{attacker} does not know the move {move_name}")]
+    MoveNotInMoveset { attacker: String, move_name: String },
+    #[error("{attacker} does not know a move with attack {attack:?}")]
+    AttackNotInMoveset { attacker: String, attack: Attack },
+    #[error("{attacker} has no move in slot {slot}")]
+    MoveSlotIsEmpty { attacker: String, slot: usize },
+    #[error("{attacker} has no power points remaining for {move_name}")]
+    NoPowerPointsRemaining { attacker: String, move_name: String },
+    #[error("Cannot flee from a trainer battle")]
+    CannotFleeTrainerBattle,
+    #[error("{attacker} cannot use this move: {violation}")]
+    MoveForbiddenByRuleset {
+        attacker: String,
+        #[source]
+        violation: RulesetViolation,
+    },
+    #[error("{monster} has fainted and cannot be switched in")]
+    FaintedMonsterCannotSwitchIn { monster: String },
 }
 
 #[cfg(test)]
 mod tests {
+    use std::rc::Rc;
+
     use mockall::predicate::eq;
     use rust_decimal::Decimal;
     use spectral::prelude::*;
 
-    use crate::combat::MockTypeEffectivenessCalculator;
-    use crate::monster::{AttackPower, Health, MonsterType};
-    use crate::Element;
+    use crate::combat::{
+        MockRandomNumberGenerator, MockStabCalculator, MockTypeEffectivenessCalculator,
+    };
+    use crate::item::{ChoiceItem, LifeOrb, ResistBerry};
+    use crate::monster::{AttackPower, Level, MonsterBuilder, MonsterType, Stats, StatusCondition};
+    use crate::species::Species;
+    use crate::{Element, Fraction};
 
     use super::*;
 
@@ -75,8 +742,33 @@ mod tests {
 
     fn under_test(
         type_effectiveness_calculator: MockTypeEffectivenessCalculator,
-    ) -> CombatService<MockTypeEffectivenessCalculator> {
-        CombatService::new(type_effectiveness_calculator)
+    ) -> CombatService<MockTypeEffectivenessCalculator, MockRandomNumberGenerator> {
+        under_test_with_rng(
+            type_effectiveness_calculator,
+            MockRandomNumberGenerator::default(),
+        )
+    }
+
+    fn under_test_with_rng(
+        type_effectiveness_calculator: MockTypeEffectivenessCalculator,
+        random_number_generator: MockRandomNumberGenerator,
+    ) -> CombatService<MockTypeEffectivenessCalculator, MockRandomNumberGenerator> {
+        CombatService::new(type_effectiveness_calculator, random_number_generator)
+    }
+
+    fn mock_random_number_generator(roll: u32) -> MockRandomNumberGenerator {
+        let mut random_number_generator = MockRandomNumberGenerator::default();
+        random_number_generator
+            .expect_next_below()
+            .with(eq(100))
+            .returning(move |_| roll);
+        random_number_generator
+    }
+
+    fn neutral_type_effectiveness_calculator() -> MockTypeEffectivenessCalculator {
+        let mut calculator = mock_type_effectiveness_calculator();
+        prepare_mock_type_effectiveness_calculator(&mut calculator, 1.into());
+        calculator
     }
 
     fn mock_type_effectiveness_calculator() -> MockTypeEffectivenessCalculator {
@@ -101,18 +793,41 @@ mod tests {
     }
 
     fn attacking_monster(primary_element: Element) -> Monster {
-        Monster::new(
+        let mut monster = Monster::new(
             MonsterType::new(primary_element, None),
             Health::new(10.into()),
-        )
+        );
+        monster
+            .moveset_mut()
+            .learn(move_definition(
+                MoveDamage::Power(AttackPower::new(5.into())),
+                Vec::new(),
+            ))
+            .unwrap();
+        monster
     }
 
     fn defending_monster(health_value: Decimal) -> Monster {
         Monster::new(monster_type(), Health::new(health_value))
     }
 
+    fn move_definition(damage: MoveDamage, effects: Vec<MoveEffect>) -> MoveDefinition {
+        MoveDefinition::new(
+            "Test Move",
+            Element::Normal,
+            AttackCategory::Physical,
+            damage,
+            10,
+            effects,
+        )
+    }
+
     fn attack() -> Attack {
-        Attack::new(Element::Normal, AttackPower::new(5.into()))
+        Attack::new(
+            Element::Normal,
+            AttackCategory::Physical,
+            AttackPower::new(5.into()),
+        )
     }
 
     #[test]
@@ -123,13 +838,13 @@ mod tests {
 
         under_test(calculator)
             .perform_attack(
-                &attacking_monster(NON_STAB_ELEMENT),
+                &mut attacking_monster(NON_STAB_ELEMENT),
                 attack(),
                 &mut defender,
             )
             .unwrap();
 
-        assert_that(defender.health()).is_equal_to(&Health::new(5.into()));
+        assert_that(defender.health()).is_equal_to(Health::new(5.into()));
     }
 
     #[test]
@@ -140,13 +855,13 @@ mod tests {
 
         under_test(calculator)
             .perform_attack(
-                &attacking_monster(NON_STAB_ELEMENT),
+                &mut attacking_monster(NON_STAB_ELEMENT),
                 attack(),
                 &mut defender,
             )
             .unwrap();
 
-        assert_that(defender.health()).is_equal_to(&Health::new(10.into()));
+        assert_that(defender.health()).is_equal_to(Health::new(10.into()));
     }
 
     #[test]
@@ -156,22 +871,1128 @@ mod tests {
         prepare_mock_type_effectiveness_calculator(&mut calculator, 1.into());
 
         under_test(calculator)
-            .perform_attack(&attacking_monster(Element::Normal), attack(), &mut defender)
+            .perform_attack(
+                &mut attacking_monster(Element::Normal),
+                attack(),
+                &mut defender,
+            )
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(Decimal::new(25, 1)));
+    }
+
+    #[test]
+    fn applies_multiplier_from_configured_stab_calculator() {
+        let mut defender = defending_monster(20.into());
+        let mut stab_calculator = MockStabCalculator::default();
+        stab_calculator
+            .expect_calculate()
+            .returning(|_, _| DamageMultiplier::new(3.into()));
+
+        under_test(neutral_type_effectiveness_calculator())
+            .with_stab_calculator(stab_calculator)
+            .perform_attack(
+                &mut attacking_monster(NON_STAB_ELEMENT),
+                attack(),
+                &mut defender,
+            )
             .unwrap();
 
-        assert_that(defender.health()).is_equal_to(&Health::new(Decimal::new(25, 1)));
+        assert_that(defender.health()).is_equal_to(Health::new(5.into()));
     }
 
     #[test]
     fn fails_to_perform_attack_on_already_defeated_defender() {
         assert_that(&matches!(
             under_test(mock_type_effectiveness_calculator()).perform_attack(
-                &attacking_monster(NON_STAB_ELEMENT),
+                &mut attacking_monster(NON_STAB_ELEMENT),
                 attack(),
                 &mut defending_monster(0.into())
             ),
-            Err(CombatError::DefenderIsAlreadyDefeated)
+            Err(CombatError::DefenderIsAlreadyDefeated { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn fails_to_perform_attack_with_fainted_attacker() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.receive_damage(Damage::new(10.into()));
+
+        assert_that(&matches!(
+            under_test(mock_type_effectiveness_calculator()).perform_attack(
+                &mut attacker,
+                attack(),
+                &mut defending_monster(10.into())
+            ),
+            Err(CombatError::AttackerIsFainted { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn fails_to_perform_attack_with_sleeping_attacker() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.inflict_status(StatusCondition::Sleep);
+
+        assert_that(&matches!(
+            under_test(mock_type_effectiveness_calculator()).perform_attack(
+                &mut attacker,
+                attack(),
+                &mut defending_monster(10.into())
+            ),
+            Err(CombatError::AttackerIsAsleep { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn sleeping_attacker_wakes_once_sleep_turns_run_out() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.inflict_sleep(1);
+        let mut defender = defending_monster(10.into());
+        let combat_service = under_test(neutral_type_effectiveness_calculator());
+
+        assert_that(&matches!(
+            combat_service.perform_attack(&mut attacker, attack(), &mut defender),
+            Err(CombatError::AttackerIsAsleep { .. })
+        ))
+        .is_true();
+        combat_service
+            .perform_attack(&mut attacker, attack(), &mut defender)
+            .unwrap();
+
+        assert_that(&attacker.status()).is_none();
+        assert_that(defender.health()).is_equal_to(Health::new(5.into()));
+    }
+
+    #[test]
+    fn fails_to_perform_attack_with_frozen_attacker() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.inflict_status(StatusCondition::Freeze);
+
+        assert_that(&matches!(
+            under_test_with_rng(
+                mock_type_effectiveness_calculator(),
+                mock_random_number_generator(20)
+            )
+            .perform_attack(&mut attacker, attack(), &mut defending_monster(10.into())),
+            Err(CombatError::AttackerIsFrozen { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn frozen_attacker_thaws_when_thaw_roll_succeeds() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.inflict_status(StatusCondition::Freeze);
+        let mut defender = defending_monster(10.into());
+
+        under_test_with_rng(
+            neutral_type_effectiveness_calculator(),
+            mock_random_number_generator(19),
+        )
+        .perform_attack(&mut attacker, attack(), &mut defender)
+        .unwrap();
+
+        assert_that(&attacker.status()).is_none();
+        assert_that(defender.health()).is_equal_to(Health::new(5.into()));
+    }
+
+    #[test]
+    fn fails_to_perform_attack_with_flinched_attacker() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.flinch();
+
+        assert_that(&matches!(
+            under_test(mock_type_effectiveness_calculator()).perform_attack(
+                &mut attacker,
+                attack(),
+                &mut defending_monster(10.into())
+            ),
+            Err(CombatError::AttackerIsFlinched { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn fails_to_perform_attack_not_in_attacker_moveset() {
+        let unknown_attack = Attack::new(
+            Element::Fire,
+            AttackCategory::Special,
+            AttackPower::new(90.into()),
+        );
+
+        assert_that(&matches!(
+            under_test(mock_type_effectiveness_calculator()).perform_attack(
+                &mut attacking_monster(NON_STAB_ELEMENT),
+                unknown_attack.clone(),
+                &mut defending_monster(10.into())
+            ),
+            Err(CombatError::AttackNotInMoveset { attack, .. }) if attack == unknown_attack
         ))
         .is_true();
     }
+
+    #[test]
+    fn rejected_attack_does_not_tick_sleep_or_roll_thaw() {
+        let unknown_attack = Attack::new(
+            Element::Fire,
+            AttackCategory::Special,
+            AttackPower::new(90.into()),
+        );
+        let mut sleeping = attacking_monster(NON_STAB_ELEMENT);
+        sleeping.inflict_sleep(2);
+        let mut frozen = attacking_monster(NON_STAB_ELEMENT);
+        frozen.inflict_status(StatusCondition::Freeze);
+        let combat_service = under_test(mock_type_effectiveness_calculator());
+
+        for attacker in [&mut sleeping, &mut frozen] {
+            assert_that(&matches!(
+                combat_service.perform_attack(
+                    attacker,
+                    unknown_attack.clone(),
+                    &mut defending_monster(10.into())
+                ),
+                Err(CombatError::AttackNotInMoveset { .. })
+            ))
+            .is_true();
+        }
+
+        assert_that(&sleeping.sleep_turns()).is_equal_to(2);
+        assert_that(&frozen.status()).is_equal_to(Some(&StatusCondition::Freeze));
+    }
+
+    #[test]
+    fn fails_to_perform_move_not_in_attacker_moveset() {
+        let unknown_move = MoveDefinition::new(
+            "Unknown Move",
+            Element::Normal,
+            AttackCategory::Physical,
+            MoveDamage::Power(AttackPower::new(5.into())),
+            10,
+            Vec::new(),
+        );
+
+        let error = under_test(mock_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacking_monster(NON_STAB_ELEMENT),
+                &unknown_move,
+                &mut defending_monster(10.into()),
+                &[],
+            )
+            .unwrap_err();
+
+        assert_that(&error.to_string())
+            .is_equal_to("Unknown monster does not know the move Unknown Move".to_string());
+    }
+
+    #[test]
+    fn fails_to_perform_move_with_no_pp_remaining() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        for _ in 0..10 {
+            attacker.moveset_mut().slot_mut(0).unwrap().deduct_pp();
+        }
+
+        assert_that(&matches!(
+            under_test(mock_type_effectiveness_calculator()).perform_move(
+                &mut attacker,
+                &move_definition(MoveDamage::Power(AttackPower::new(5.into())), Vec::new()),
+                &mut defending_monster(10.into()), &[]
+            ),
+            Err(CombatError::NoPowerPointsRemaining { move_name, .. }) if move_name == "Test Move"
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn records_event_when_defender_faints() {
+        let outcome = under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacking_monster(NON_STAB_ELEMENT),
+                &move_definition(MoveDamage::Power(AttackPower::new(5.into())), Vec::new()),
+                &mut defending_monster(5.into()),
+                &[],
+            )
+            .unwrap();
+
+        assert_that(&outcome.events()).is_equal_to(&[CombatEvent::DefenderFainted][..]);
+    }
+
+    #[test]
+    fn records_event_when_attacker_faints_from_recoil() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.receive_damage(Damage::new(9.into()));
+
+        let outcome = under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacker,
+                &move_definition(
+                    MoveDamage::Power(AttackPower::new(8.into())),
+                    vec![MoveEffect::Recoil(Fraction::new(1, 4))],
+                ),
+                &mut defending_monster(20.into()),
+                &[],
+            )
+            .unwrap();
+
+        assert_that(&attacker.is_fainted()).is_true();
+        assert_that(&outcome.events()).is_equal_to(&[CombatEvent::AttackerFainted][..]);
+    }
+
+    #[test]
+    fn fainted_monster_cannot_be_switched_in() {
+        let mut fainted = defending_monster(10.into());
+        fainted.receive_damage(Damage::new(10.into()));
+
+        assert_that(&matches!(
+            under_test(mock_type_effectiveness_calculator())
+                .switch(&mut attacking_monster(NON_STAB_ELEMENT), &fainted),
+            Err(CombatError::FaintedMonsterCannotSwitchIn { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn switching_resets_outgoing_stat_stages() {
+        let mut outgoing = attacking_monster(NON_STAB_ELEMENT);
+        outgoing.change_stat_stage(BattleStat::Attack, 2);
+
+        under_test(mock_type_effectiveness_calculator())
+            .switch(&mut outgoing, &defending_monster(10.into()))
+            .unwrap();
+
+        assert_that(&outgoing.stat_stages().stage(BattleStat::Attack)).is_equal_to(0);
+    }
+
+    #[test]
+    fn applies_attacker_held_item_multiplier_to_resultant_damage() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.give_held_item(Box::new(LifeOrb::new()));
+        let mut defender = defending_monster(20.into());
+        let mut calculator = mock_type_effectiveness_calculator();
+        prepare_mock_type_effectiveness_calculator(&mut calculator, 1.into());
+
+        under_test(calculator)
+            .perform_attack(&mut attacker, attack(), &mut defender)
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(Decimal::new(135, 1)));
+        assert_that(attacker.health()).is_equal_to(Health::new(9.into()));
+    }
+
+    #[test]
+    fn applies_defender_held_item_multiplier_and_consumes_activated_item() {
+        let mut defender = defending_monster(20.into());
+        defender.give_held_item(Box::new(ResistBerry::new(Element::Normal)));
+        let mut calculator = mock_type_effectiveness_calculator();
+        prepare_mock_type_effectiveness_calculator(&mut calculator, 1.into());
+
+        under_test(calculator)
+            .perform_attack(
+                &mut attacking_monster(NON_STAB_ELEMENT),
+                attack(),
+                &mut defender,
+            )
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(Decimal::new(175, 1)));
+        assert_that(&defender.held_item().is_none()).is_true();
+    }
+
+    #[test]
+    fn fails_to_perform_attack_not_permitted_by_attacker_held_item() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        let other_move = MoveDefinition::new(
+            "Other Move",
+            Element::Normal,
+            AttackCategory::Special,
+            MoveDamage::Power(AttackPower::new(5.into())),
+            10,
+            Vec::new(),
+        );
+        attacker.moveset_mut().learn(other_move.clone()).unwrap();
+        attacker.give_held_item(Box::new(ChoiceItem::band()));
+        let mut calculator = mock_type_effectiveness_calculator();
+        prepare_mock_type_effectiveness_calculator(&mut calculator, 1.into());
+        let combat_service = under_test(calculator);
+        combat_service
+            .perform_attack(&mut attacker, attack(), &mut defending_monster(20.into()))
+            .unwrap();
+
+        assert_that(&matches!(
+            combat_service.perform_attack(
+                &mut attacker,
+                other_move.attack(),
+                &mut defending_monster(20.into())
+            ),
+            Err(CombatError::MoveNotPermittedByHeldItem { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn choice_item_locks_even_when_move_deals_no_damage() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        let twin_move = MoveDefinition::new(
+            "Twin Move",
+            Element::Normal,
+            AttackCategory::Physical,
+            MoveDamage::Power(AttackPower::new(5.into())),
+            10,
+            Vec::new(),
+        );
+        attacker.moveset_mut().learn(twin_move.clone()).unwrap();
+        attacker.give_held_item(Box::new(ChoiceItem::band()));
+        let combat_service = under_test(neutral_type_effectiveness_calculator());
+        let test_move = attacker.moveset().slots()[0].move_definition().clone();
+        let mut immune_calculator = mock_type_effectiveness_calculator();
+        prepare_mock_type_effectiveness_calculator(&mut immune_calculator, 0.into());
+        under_test(immune_calculator)
+            .perform_move(
+                &mut attacker,
+                &test_move,
+                &mut defending_monster(20.into()),
+                &[],
+            )
+            .unwrap();
+
+        assert_that(&matches!(
+            combat_service.perform_move(
+                &mut attacker,
+                &twin_move,
+                &mut defending_monster(20.into()),
+                &[]
+            ),
+            Err(CombatError::MoveNotPermittedByHeldItem { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn applies_attacker_stat_stages_to_resultant_damage() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.change_stat_stage(BattleStat::Attack, 2);
+        let mut defender = defending_monster(20.into());
+
+        under_test(neutral_type_effectiveness_calculator())
+            .perform_attack(&mut attacker, attack(), &mut defender)
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(10.into()));
+    }
+
+    #[test]
+    fn performs_move_with_power_on_defender() {
+        let mut defender = defending_monster(20.into());
+
+        let outcome = under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacking_monster(NON_STAB_ELEMENT),
+                &move_definition(MoveDamage::Power(AttackPower::new(5.into())), Vec::new()),
+                &mut defender,
+                &[],
+            )
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(15.into()));
+        assert_that(&outcome.hits()).is_equal_to(1);
+        assert_that(outcome.damage_dealt()).is_equal_to(Damage::new(5.into()));
+    }
+
+    #[test]
+    fn multi_hit_move_hits_according_to_standard_distribution() {
+        let mut defender = defending_monster(100.into());
+
+        let outcome = under_test_with_rng(
+            neutral_type_effectiveness_calculator(),
+            mock_random_number_generator(80),
+        )
+        .perform_move(
+            &mut attacking_monster(NON_STAB_ELEMENT),
+            &move_definition(
+                MoveDamage::Power(AttackPower::new(5.into())),
+                vec![MoveEffect::MultiHit(HitCount::TwoToFive)],
+            ),
+            &mut defender,
+            &[],
+        )
+        .unwrap();
+
+        assert_that(&outcome.hits()).is_equal_to(4);
+        assert_that(defender.health()).is_equal_to(Health::new(80.into()));
+    }
+
+    #[test]
+    fn multi_hit_move_stops_when_defender_is_defeated() {
+        let outcome = under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacking_monster(NON_STAB_ELEMENT),
+                &move_definition(
+                    MoveDamage::Power(AttackPower::new(5.into())),
+                    vec![MoveEffect::MultiHit(HitCount::Fixed(3))],
+                ),
+                &mut defending_monster(8.into()),
+                &[],
+            )
+            .unwrap();
+
+        assert_that(&outcome.hits()).is_equal_to(2);
+        assert_that(outcome.damage_dealt()).is_equal_to(Damage::new(8.into()));
+    }
+
+    #[test]
+    fn inflicts_status_when_chance_roll_succeeds() {
+        let mut defender = defending_monster(20.into());
+        let effect = MoveEffect::InflictStatus {
+            status: StatusCondition::Burn,
+            chance: Chance::percent(10),
+        };
+
+        let outcome = under_test_with_rng(
+            neutral_type_effectiveness_calculator(),
+            mock_random_number_generator(9),
+        )
+        .perform_move(
+            &mut attacking_monster(NON_STAB_ELEMENT),
+            &move_definition(MoveDamage::Power(AttackPower::new(5.into())), vec![effect]),
+            &mut defender,
+            &[],
+        )
+        .unwrap();
+
+        assert_that(&defender.status()).is_equal_to(Some(&StatusCondition::Burn));
+        assert_that(&outcome.applied_effects()).is_equal_to([effect].as_ref());
+    }
+
+    #[test]
+    fn does_not_flinch_when_chance_roll_fails() {
+        let mut defender = defending_monster(20.into());
+
+        under_test_with_rng(
+            neutral_type_effectiveness_calculator(),
+            mock_random_number_generator(30),
+        )
+        .perform_move(
+            &mut attacking_monster(NON_STAB_ELEMENT),
+            &move_definition(
+                MoveDamage::Power(AttackPower::new(5.into())),
+                vec![MoveEffect::Flinch {
+                    chance: Chance::percent(30),
+                }],
+            ),
+            &mut defender,
+            &[],
+        )
+        .unwrap();
+
+        assert_that(&defender.is_flinched()).is_false();
+    }
+
+    #[test]
+    fn status_move_changes_user_stat_stages() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        let mut defender = defending_monster(20.into());
+
+        under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacker,
+                &move_definition(
+                    MoveDamage::None,
+                    vec![MoveEffect::StatChange {
+                        target: EffectTarget::User,
+                        stat: BattleStat::Attack,
+                        stages: 2,
+                        chance: Chance::always(),
+                    }],
+                ),
+                &mut defender,
+                &[],
+            )
+            .unwrap();
+
+        assert_that(&attacker.stat_stages().stage(BattleStat::Attack)).is_equal_to(2);
+        assert_that(defender.health()).is_equal_to(Health::new(20.into()));
+    }
+
+    #[test]
+    fn recoil_is_fraction_of_damage_dealt() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+
+        under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacker,
+                &move_definition(
+                    MoveDamage::Power(AttackPower::new(8.into())),
+                    vec![MoveEffect::Recoil(Fraction::new(1, 4))],
+                ),
+                &mut defending_monster(20.into()),
+                &[],
+            )
+            .unwrap();
+
+        assert_that(attacker.health()).is_equal_to(Health::new(8.into()));
+    }
+
+    #[test]
+    fn drain_restores_fraction_of_damage_dealt() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.receive_damage(Damage::new(5.into()));
+
+        under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacker,
+                &move_definition(
+                    MoveDamage::Power(AttackPower::new(4.into())),
+                    vec![MoveEffect::Drain(Fraction::new(1, 2))],
+                ),
+                &mut defending_monster(20.into()),
+                &[],
+            )
+            .unwrap();
+
+        assert_that(attacker.health()).is_equal_to(Health::new(7.into()));
+    }
+
+    #[test]
+    fn heal_restores_fraction_of_max_health() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.receive_damage(Damage::new(8.into()));
+
+        under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacker,
+                &move_definition(
+                    MoveDamage::None,
+                    vec![MoveEffect::Heal(Fraction::new(1, 2))],
+                ),
+                &mut defending_monster(20.into()),
+                &[],
+            )
+            .unwrap();
+
+        assert_that(attacker.health()).is_equal_to(Health::new(7.into()));
+    }
+
+    #[test]
+    fn fixed_damage_ignores_multipliers() {
+        let mut calculator = mock_type_effectiveness_calculator();
+        prepare_mock_type_effectiveness_calculator(&mut calculator, 2.into());
+        let mut defender = defending_monster(50.into());
+
+        under_test(calculator)
+            .perform_move(
+                &mut attacking_monster(Element::Normal),
+                &move_definition(MoveDamage::Fixed(Damage::new(40.into())), Vec::new()),
+                &mut defender,
+                &[],
+            )
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(10.into()));
+    }
+
+    #[test]
+    fn level_based_damage_equals_attacker_level() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+        attacker.set_level(Level::new(30));
+        let mut defender = defending_monster(50.into());
+
+        under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacker,
+                &move_definition(MoveDamage::Level, Vec::new()),
+                &mut defender,
+                &[],
+            )
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(20.into()));
+    }
+
+    #[test]
+    fn one_hit_knock_out_defeats_defender() {
+        let mut defender = defending_monster(50.into());
+
+        under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacking_monster(NON_STAB_ELEMENT),
+                &move_definition(MoveDamage::OneHitKnockOut, Vec::new()),
+                &mut defender,
+                &[],
+            )
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(0.into()));
+    }
+
+    #[test]
+    fn one_hit_knock_out_fails_against_higher_level_defender() {
+        let mut defender = defending_monster(50.into());
+        defender.set_level(Level::new(2));
+
+        under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacking_monster(NON_STAB_ELEMENT),
+                &move_definition(MoveDamage::OneHitKnockOut, Vec::new()),
+                &mut defender,
+                &[],
+            )
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(50.into()));
+    }
+
+    #[test]
+    fn damaging_move_has_no_effect_on_immune_defender() {
+        let mut calculator = mock_type_effectiveness_calculator();
+        prepare_mock_type_effectiveness_calculator(&mut calculator, 0.into());
+        let mut defender = defending_monster(50.into());
+
+        let outcome = under_test(calculator)
+            .perform_move(
+                &mut attacking_monster(NON_STAB_ELEMENT),
+                &move_definition(
+                    MoveDamage::Level,
+                    vec![MoveEffect::Flinch {
+                        chance: Chance::always(),
+                    }],
+                ),
+                &mut defender,
+                &[],
+            )
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(50.into()));
+        assert_that(&outcome.applied_effects().is_empty()).is_true();
+    }
+
+    fn attacker_knowing(move_definition: MoveDefinition) -> Monster {
+        let mut attacker = Monster::new(
+            MonsterType::new(NON_STAB_ELEMENT, None),
+            Health::new(10.into()),
+        );
+        attacker.moveset_mut().learn(move_definition).unwrap();
+        attacker
+    }
+
+    #[test]
+    fn using_move_deducts_pp() {
+        let mut attacker = attacker_knowing(move_definition(
+            MoveDamage::Power(AttackPower::new(5.into())),
+            Vec::new(),
+        ));
+
+        under_test(neutral_type_effectiveness_calculator())
+            .use_move(&mut attacker, 0, &mut defending_monster(20.into()), &[])
+            .unwrap();
+
+        assert_that(&attacker.moveset().slot(0).unwrap().current_pp()).is_equal_to(9);
+    }
+
+    #[test]
+    fn performing_move_deducts_pp_once() {
+        let power_move = move_definition(MoveDamage::Power(AttackPower::new(5.into())), Vec::new());
+        let mut attacker = attacker_knowing(power_move.clone());
+
+        under_test(neutral_type_effectiveness_calculator())
+            .perform_move(
+                &mut attacker,
+                &power_move,
+                &mut defending_monster(20.into()),
+                &[],
+            )
+            .unwrap();
+
+        assert_that(&attacker.moveset().slot(0).unwrap().current_pp()).is_equal_to(9);
+    }
+
+    #[test]
+    fn performing_attack_deducts_pp() {
+        let mut attacker = attacking_monster(NON_STAB_ELEMENT);
+
+        under_test(neutral_type_effectiveness_calculator())
+            .perform_attack(&mut attacker, attack(), &mut defending_monster(20.into()))
+            .unwrap();
+
+        assert_that(&attacker.moveset().slot(0).unwrap().current_pp()).is_equal_to(9);
+    }
+
+    #[test]
+    fn fails_to_use_move_with_no_pp_remaining() {
+        let mut attacker = attacker_knowing(move_definition(
+            MoveDamage::Power(AttackPower::new(5.into())),
+            Vec::new(),
+        ));
+        attacker
+            .moveset_mut()
+            .learn(MoveDefinition::new(
+                "Other Move",
+                Element::Normal,
+                AttackCategory::Physical,
+                MoveDamage::None,
+                10,
+                Vec::new(),
+            ))
+            .unwrap();
+        (0..10).for_each(|_| attacker.moveset_mut().slot_mut(0).unwrap().deduct_pp());
+
+        assert_that(&matches!(
+            under_test(neutral_type_effectiveness_calculator()).use_move(
+                &mut attacker,
+                0,
+                &mut defending_monster(20.into()),
+                &[]
+            ),
+            Err(CombatError::NoPowerPointsRemaining { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn fails_to_use_empty_move_slot() {
+        let mut attacker = attacker_knowing(move_definition(MoveDamage::None, Vec::new()));
+
+        assert_that(&matches!(
+            under_test(neutral_type_effectiveness_calculator()).use_move(
+                &mut attacker,
+                3,
+                &mut defending_monster(20.into()),
+                &[]
+            ),
+            Err(CombatError::MoveSlotIsEmpty { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn struggles_when_all_pp_is_exhausted() {
+        let mut attacker = attacker_knowing(move_definition(MoveDamage::None, Vec::new()));
+        (0..10).for_each(|_| attacker.moveset_mut().slot_mut(0).unwrap().deduct_pp());
+        let mut defender = defending_monster(80.into());
+
+        under_test(mock_type_effectiveness_calculator())
+            .use_move(&mut attacker, 0, &mut defender, &[])
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(30.into()));
+        assert_that(attacker.health()).is_equal_to(Health::new(Decimal::new(75, 1)));
+    }
+
+    #[test]
+    fn struggles_when_no_moves_are_known() {
+        let mut attacker = Monster::new(monster_type(), Health::new(10.into()));
+        let mut defender = defending_monster(80.into());
+
+        under_test(mock_type_effectiveness_calculator())
+            .use_move(&mut attacker, 0, &mut defender, &[])
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(30.into()));
+    }
+
+    fn monster_with_speed(speed: u16) -> Monster {
+        let species = Rc::new(Species::new(
+            "Test Species",
+            monster_type(),
+            Stats::new(50, 50, 50, 50, 50, speed),
+            Vec::new(),
+            Vec::new(),
+        ));
+        MonsterBuilder::new(species, Level::new(50))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn faster_runner_always_flees() {
+        assert_that(
+            &under_test(mock_type_effectiveness_calculator())
+                .attempt_flee(
+                    &monster_with_speed(100),
+                    &monster_with_speed(50),
+                    1,
+                    BattleKind::Wild,
+                )
+                .unwrap(),
+        )
+        .is_true();
+    }
+
+    #[test]
+    fn slower_runner_flees_when_roll_is_below_escape_odds() {
+        let mut random_number_generator = MockRandomNumberGenerator::default();
+        random_number_generator
+            .expect_next_below()
+            .with(eq(256))
+            .returning(|_| 110);
+        let combat_service = under_test_with_rng(
+            mock_type_effectiveness_calculator(),
+            random_number_generator,
+        );
+        let runner = monster_with_speed(50);
+        let opponent = monster_with_speed(100);
+
+        assert_that(
+            &combat_service
+                .attempt_flee(&runner, &opponent, 1, BattleKind::Wild)
+                .unwrap(),
+        )
+        .is_false();
+        assert_that(
+            &combat_service
+                .attempt_flee(&runner, &opponent, 2, BattleKind::Wild)
+                .unwrap(),
+        )
+        .is_true();
+    }
+
+    #[test]
+    fn cannot_flee_from_trainer_battle() {
+        assert_that(&matches!(
+            under_test(mock_type_effectiveness_calculator()).attempt_flee(
+                &monster_with_speed(100),
+                &monster_with_speed(50),
+                1,
+                BattleKind::Trainer
+            ),
+            Err(CombatError::CannotFleeTrainerBattle)
+        ))
+        .is_true();
+    }
+
+    fn fixed_point_combat_service(
+        type_effectiveness_multiplier: Decimal,
+        damage_roll: u32,
+    ) -> CombatService<MockTypeEffectivenessCalculator, MockRandomNumberGenerator> {
+        let mut calculator = mock_type_effectiveness_calculator();
+        prepare_mock_type_effectiveness_calculator(&mut calculator, type_effectiveness_multiplier);
+        let mut random_number_generator = MockRandomNumberGenerator::default();
+        random_number_generator
+            .expect_next_below()
+            .with(eq(16))
+            .returning(move |_| damage_roll);
+        under_test_with_rng(calculator, random_number_generator)
+            .with_arithmetic_mode(ArithmeticMode::FixedPoint)
+            .with_damage_formula(DamageFormula::Standard)
+    }
+
+    fn level_fifty_monster() -> Monster {
+        let species = Rc::new(Species::new(
+            "Test Species",
+            monster_type(),
+            Stats::uniform(100),
+            Vec::new(),
+            Vec::new(),
+        ));
+        let mut monster = MonsterBuilder::new(species, Level::new(50))
+            .build()
+            .unwrap();
+        monster.moveset_mut().learn(power_move(80)).unwrap();
+        monster
+    }
+
+    fn power_move(power: u8) -> MoveDefinition {
+        move_definition(
+            MoveDamage::Power(AttackPower::new(power.into())),
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn defaults_to_decimal_arithmetic_and_power_formula() {
+        let combat_service = under_test(mock_type_effectiveness_calculator());
+
+        assert_that(&combat_service.arithmetic_mode()).is_equal_to(ArithmeticMode::Decimal);
+        assert_that(&combat_service.damage_formula()).is_equal_to(DamageFormula::Power);
+    }
+
+    #[test]
+    fn fixed_point_mode_only_changes_rounding_of_power_formula() {
+        let mut calculator = mock_type_effectiveness_calculator();
+        prepare_mock_type_effectiveness_calculator(&mut calculator, Decimal::new(5, 1));
+        let mut attacker = attacker_knowing(power_move(15));
+        let mut defender = defending_monster(80.into());
+
+        under_test(calculator)
+            .with_arithmetic_mode(ArithmeticMode::FixedPoint)
+            .perform_move(&mut attacker, &power_move(15), &mut defender, &[])
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(73.into()));
+    }
+
+    #[test]
+    fn fixed_point_damage_follows_reference_formula_with_highest_roll() {
+        let mut attacker = level_fifty_monster();
+        let mut defender = level_fifty_monster();
+
+        let outcome = fixed_point_combat_service(1.into(), 15)
+            .perform_move(&mut attacker, &power_move(80), &mut defender, &[])
+            .unwrap();
+
+        assert_that(outcome.damage_dealt()).is_equal_to(Damage::new(55.into()));
+        assert_that(defender.health()).is_equal_to(Health::new(105.into()));
+    }
+
+    #[test]
+    fn fixed_point_damage_follows_reference_formula_with_lowest_roll() {
+        let mut attacker = level_fifty_monster();
+        let mut defender = level_fifty_monster();
+
+        fixed_point_combat_service(1.into(), 0)
+            .perform_move(&mut attacker, &power_move(80), &mut defender, &[])
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(114.into()));
+    }
+
+    #[test]
+    fn fixed_point_damage_rounds_at_each_modifier_step() {
+        let mut attacker = level_fifty_monster();
+        attacker.give_held_item(Box::new(LifeOrb::new()));
+        let mut defender = level_fifty_monster();
+
+        fixed_point_combat_service(2.into(), 15)
+            .perform_move(&mut attacker, &power_move(80), &mut defender, &[])
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(17.into()));
+        assert_that(attacker.health()).is_equal_to(Health::new(144.into()));
+    }
+
+    #[test]
+    fn fixed_point_damage_applies_stat_stages_to_stats() {
+        let mut attacker = level_fifty_monster();
+        attacker.change_stat_stage(BattleStat::Attack, 2);
+        let mut defender = level_fifty_monster();
+
+        fixed_point_combat_service(1.into(), 15)
+            .perform_move(&mut attacker, &power_move(80), &mut defender, &[])
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(52.into()));
+    }
+
+    #[test]
+    fn fixed_point_damage_is_at_least_one() {
+        let mut attacker = level_fifty_monster();
+        let mut defender = level_fifty_monster();
+
+        fixed_point_combat_service(Decimal::new(25, 2), 0)
+            .perform_move(&mut attacker, &power_move(1), &mut defender, &[])
+            .unwrap();
+
+        assert_that(defender.health()).is_equal_to(Health::new(159.into()));
+    }
+
+    #[test]
+    fn ruleset_clause_forbids_move_at_runtime() {
+        let one_hit_knock_out = move_definition(MoveDamage::OneHitKnockOut, Vec::new());
+
+        assert_that(&matches!(
+            under_test(neutral_type_effectiveness_calculator())
+                .with_ruleset(Ruleset::singles_six_versus_six())
+                .perform_move(
+                    &mut attacker_knowing(one_hit_knock_out.clone()),
+                    &one_hit_knock_out,
+                    &mut defending_monster(10.into()),
+                    &[]
+                ),
+            Err(CombatError::MoveForbiddenByRuleset {
+                violation: RulesetViolation::MoveForbiddenByClause {
+                    clause: Clause::OneHitKnockOut,
+                    ..
+                },
+                ..
+            })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn sleep_clause_prevents_putting_second_teammate_to_sleep() {
+        let hypnosis = move_definition(
+            MoveDamage::None,
+            vec![MoveEffect::InflictStatus {
+                status: StatusCondition::Sleep,
+                chance: Chance::always(),
+            }],
+        );
+        let mut sleeping_teammate = defending_monster(10.into());
+        sleeping_teammate.inflict_status(StatusCondition::Sleep);
+        let mut defender = defending_monster(10.into());
+
+        let outcome = under_test(neutral_type_effectiveness_calculator())
+            .with_ruleset(Ruleset::singles_six_versus_six())
+            .perform_move(
+                &mut attacker_knowing(hypnosis.clone()),
+                &hypnosis,
+                &mut defender,
+                &[sleeping_teammate],
+            )
+            .unwrap();
+
+        assert_that(&outcome.applied_effects().is_empty()).is_true();
+        assert_that(&defender.status()).is_none();
+    }
+
+    #[test]
+    fn sleep_clause_applies_when_using_move_from_slot() {
+        let hypnosis = move_definition(
+            MoveDamage::None,
+            vec![MoveEffect::InflictStatus {
+                status: StatusCondition::Sleep,
+                chance: Chance::always(),
+            }],
+        );
+        let mut sleeping_teammate = defending_monster(10.into());
+        sleeping_teammate.inflict_status(StatusCondition::Sleep);
+        let mut defender = defending_monster(10.into());
+
+        under_test(neutral_type_effectiveness_calculator())
+            .with_ruleset(Ruleset::singles_six_versus_six())
+            .use_move(
+                &mut attacker_knowing(hypnosis),
+                0,
+                &mut defender,
+                &[sleeping_teammate],
+            )
+            .unwrap();
+
+        assert_that(&defender.status()).is_none();
+    }
+
+    #[test]
+    fn sleep_is_inflicted_when_no_teammate_is_asleep() {
+        let hypnosis = move_definition(
+            MoveDamage::None,
+            vec![MoveEffect::InflictStatus {
+                status: StatusCondition::Sleep,
+                chance: Chance::always(),
+            }],
+        );
+        let mut defender = defending_monster(10.into());
+        let mut random_number_generator = MockRandomNumberGenerator::default();
+        random_number_generator
+            .expect_next_below()
+            .with(eq(3))
+            .returning(|_| 1);
+
+        under_test_with_rng(
+            neutral_type_effectiveness_calculator(),
+            random_number_generator,
+        )
+        .with_ruleset(Ruleset::singles_six_versus_six())
+        .perform_move(
+            &mut attacker_knowing(hypnosis.clone()),
+            &hypnosis,
+            &mut defender,
+            &[defending_monster(10.into())],
+        )
+        .unwrap();
+
+        assert_that(&defender.status()).is_equal_to(Some(&StatusCondition::Sleep));
+        assert_that(&defender.sleep_turns()).is_equal_to(2);
+    }
 }
@@ -0,0 +1,8 @@
+/// `Power` scales the move's power by the attacker's and defender's stat stages. `Standard`
+/// uses the reference games' level and stat formula followed by an 85-100% damage roll.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum DamageFormula {
+    #[default]
+    Power,
+    Standard,
+}
@@ -0,0 +1,467 @@
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+pub use modifiers::*;
+
+use crate::combat::arithmetic::{apply_modifier, chain_modifiers, to_fixed_point, FIXED_POINT_ONE};
+use crate::combat::{ArithmeticMode, DamageMultiplier, Field};
+use crate::item::HolderRole;
+use crate::monster::{Attack, Damage, Monster};
+
+mod modifiers;
+
+/// Stages run in the order the reference games apply their modifiers: weather, critical hits,
+/// the random roll, STAB, type effectiveness, burn and finally the chained item, ability and
+/// screen modifiers. In fixed-point mode type effectiveness is floored, final modifiers are
+/// chained before being applied once, and every other stage is rounded half down.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub enum ModifierStage {
+    Weather,
+    Critical,
+    Random,
+    Stab,
+    TypeEffectiveness,
+    Burn,
+    Final,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum DamageModification {
+    Unchanged,
+    Multiplier(DamageMultiplier),
+    Flat(Decimal),
+}
+
+pub struct DamageModifierContext<'a> {
+    attacker: &'a Monster,
+    defender: &'a Monster,
+    attack: &'a Attack,
+    field: &'a Field,
+    stab_multiplier: DamageMultiplier,
+    type_effectiveness: DamageMultiplier,
+}
+
+impl<'a> DamageModifierContext<'a> {
+    pub fn new(
+        attacker: &'a Monster,
+        defender: &'a Monster,
+        attack: &'a Attack,
+        field: &'a Field,
+        stab_multiplier: DamageMultiplier,
+        type_effectiveness: DamageMultiplier,
+    ) -> Self {
+        DamageModifierContext {
+            attacker,
+            defender,
+            attack,
+            field,
+            stab_multiplier,
+            type_effectiveness,
+        }
+    }
+
+    pub fn attacker(&self) -> &Monster {
+        self.attacker
+    }
+
+    pub fn defender(&self) -> &Monster {
+        self.defender
+    }
+
+    pub fn attack(&self) -> &Attack {
+        self.attack
+    }
+
+    pub fn field(&self) -> &Field {
+        self.field
+    }
+
+    pub fn stab_multiplier(&self) -> DamageMultiplier {
+        self.stab_multiplier
+    }
+
+    pub fn type_effectiveness(&self) -> DamageMultiplier {
+        self.type_effectiveness
+    }
+}
+
+pub trait DamageModifier {
+    fn name(&self) -> &str;
+    fn stage(&self) -> ModifierStage;
+    fn modify(&self, context: &DamageModifierContext) -> DamageModification;
+}
+
+pub struct DamagePipeline {
+    modifiers: Vec<Box<dyn DamageModifier>>,
+}
+
+impl DamagePipeline {
+    pub fn new() -> Self {
+        DamagePipeline {
+            modifiers: Vec::new(),
+        }
+    }
+
+    pub fn standard() -> Self {
+        DamagePipeline::new()
+            .with_modifier(Box::new(WeatherModifier))
+            .with_modifier(Box::new(StabModifier))
+            .with_modifier(Box::new(TypeEffectivenessModifier))
+            .with_modifier(Box::new(BurnModifier))
+            .with_modifier(Box::new(HeldItemModifier::new(HolderRole::Attacker)))
+            .with_modifier(Box::new(HeldItemModifier::new(HolderRole::Defender)))
+    }
+
+    pub fn with_modifier(mut self, modifier: Box<dyn DamageModifier>) -> Self {
+        self.register(modifier);
+        self
+    }
+
+    pub fn register(&mut self, modifier: Box<dyn DamageModifier>) {
+        let position = self
+            .modifiers
+            .iter()
+            .position(|existing| existing.stage() > modifier.stage())
+            .unwrap_or(self.modifiers.len());
+        self.modifiers.insert(position, modifier);
+    }
+
+    pub fn modifier_names(&self) -> Vec<&str> {
+        self.modifiers
+            .iter()
+            .map(|modifier| modifier.name())
+            .collect()
+    }
+
+    pub fn apply(
+        &self,
+        base_damage: Damage,
+        context: &DamageModifierContext,
+        arithmetic_mode: ArithmeticMode,
+        random_percent: Option<u32>,
+    ) -> Damage {
+        match arithmetic_mode {
+            ArithmeticMode::Decimal => self.apply_decimal(base_damage, context, random_percent),
+            ArithmeticMode::FixedPoint => Damage::new(
+                self.apply_fixed_point(Self::whole(base_damage.value()), context, random_percent)
+                    .into(),
+            ),
+        }
+    }
+
+    fn apply_decimal(
+        &self,
+        base_damage: Damage,
+        context: &DamageModifierContext,
+        random_percent: Option<u32>,
+    ) -> Damage {
+        let mut damage = base_damage.value();
+        let mut random_applied = false;
+
+        for modifier in &self.modifiers {
+            if !random_applied && modifier.stage() > ModifierStage::Random {
+                damage = Self::apply_random(damage, random_percent);
+                random_applied = true;
+            }
+            match modifier.modify(context) {
+                DamageModification::Unchanged => {}
+                DamageModification::Flat(amount) => damage += amount,
+                DamageModification::Multiplier(multiplier) => damage *= multiplier.value(),
+            }
+        }
+        if !random_applied {
+            damage = Self::apply_random(damage, random_percent);
+        }
+
+        Damage::new(damage)
+    }
+
+    fn apply_fixed_point(
+        &self,
+        base_damage: u64,
+        context: &DamageModifierContext,
+        random_percent: Option<u32>,
+    ) -> u64 {
+        let mut damage = base_damage;
+        let mut random_applied = false;
+        let mut final_multipliers = Vec::new();
+
+        for modifier in &self.modifiers {
+            if !random_applied && modifier.stage() > ModifierStage::Random {
+                damage = Self::apply_fixed_point_random(damage, random_percent);
+                random_applied = true;
+            }
+            match modifier.modify(context) {
+                DamageModification::Unchanged => {}
+                DamageModification::Flat(amount) => {
+                    damage = Self::whole(Decimal::from(damage) + amount)
+                }
+                DamageModification::Multiplier(multiplier) => match modifier.stage() {
+                    ModifierStage::Final => final_multipliers.push(to_fixed_point(&multiplier)),
+                    ModifierStage::TypeEffectiveness => {
+                        damage = damage * to_fixed_point(&multiplier) / FIXED_POINT_ONE
+                    }
+                    _ => damage = apply_modifier(damage, to_fixed_point(&multiplier)),
+                },
+            }
+        }
+        if !random_applied {
+            damage = Self::apply_fixed_point_random(damage, random_percent);
+        }
+        if !final_multipliers.is_empty() {
+            damage = apply_modifier(damage, chain_modifiers(&final_multipliers));
+        }
+
+        damage
+    }
+
+    fn apply_random(damage: Decimal, random_percent: Option<u32>) -> Decimal {
+        match random_percent {
+            Some(percent) => (damage * Decimal::from(percent) / Decimal::from(100)).floor(),
+            None => damage,
+        }
+    }
+
+    fn apply_fixed_point_random(damage: u64, random_percent: Option<u32>) -> u64 {
+        match random_percent {
+            Some(percent) => damage * u64::from(percent) / 100,
+            None => damage,
+        }
+    }
+
+    fn whole(damage: Decimal) -> u64 {
+        damage.floor().max(0.into()).to_u64().unwrap_or(0)
+    }
+}
+
+impl Default for DamagePipeline {
+    fn default() -> Self {
+        DamagePipeline::standard()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::combat::Weather;
+    use crate::item::LifeOrb;
+    use crate::monster::{AttackCategory, AttackPower, Health, MonsterType, StatusCondition};
+    use crate::Element;
+
+    use super::*;
+
+    struct FlatBonus;
+
+    impl DamageModifier for FlatBonus {
+        fn name(&self) -> &str {
+            "Flat bonus"
+        }
+
+        fn stage(&self) -> ModifierStage {
+            ModifierStage::Weather
+        }
+
+        fn modify(&self, _: &DamageModifierContext) -> DamageModification {
+            DamageModification::Flat(10.into())
+        }
+    }
+
+    struct Screen;
+
+    impl DamageModifier for Screen {
+        fn name(&self) -> &str {
+            "Screen"
+        }
+
+        fn stage(&self) -> ModifierStage {
+            ModifierStage::Final
+        }
+
+        fn modify(&self, _: &DamageModifierContext) -> DamageModification {
+            DamageModification::Multiplier(DamageMultiplier::new(Decimal::new(5, 1)))
+        }
+    }
+
+    fn monster() -> Monster {
+        Monster::new(
+            MonsterType::new(Element::Normal, None),
+            Health::new(100.into()),
+        )
+    }
+
+    fn attack(element: Element, category: AttackCategory) -> Attack {
+        Attack::new(element, category, AttackPower::new(80.into()))
+    }
+
+    fn single() -> DamageMultiplier {
+        DamageMultiplier::new(1.into())
+    }
+
+    #[test]
+    fn orders_standard_modifiers_by_stage() {
+        assert_that(&DamagePipeline::standard().modifier_names()).is_equal_to(vec![
+            "Weather",
+            "STAB",
+            "Type effectiveness",
+            "Burn",
+            "Attacker held item",
+            "Defender held item",
+        ]);
+    }
+
+    #[test]
+    fn registers_custom_modifiers_after_existing_modifiers_of_same_stage() {
+        let pipeline = DamagePipeline::standard()
+            .with_modifier(Box::new(Screen))
+            .with_modifier(Box::new(FlatBonus));
+
+        assert_that(&pipeline.modifier_names()).is_equal_to(vec![
+            "Weather",
+            "Flat bonus",
+            "STAB",
+            "Type effectiveness",
+            "Burn",
+            "Attacker held item",
+            "Defender held item",
+            "Screen",
+        ]);
+    }
+
+    #[test]
+    fn applies_flat_changes_before_later_multipliers() {
+        let (attacker, defender) = (monster(), monster());
+        let attack = attack(Element::Normal, AttackCategory::Physical);
+        let field = Field::new();
+        let context = DamageModifierContext::new(
+            &attacker,
+            &defender,
+            &attack,
+            &field,
+            DamageMultiplier::new(2.into()),
+            single(),
+        );
+
+        assert_that(
+            &DamagePipeline::standard()
+                .with_modifier(Box::new(FlatBonus))
+                .apply(
+                    Damage::new(20.into()),
+                    &context,
+                    ArithmeticMode::Decimal,
+                    None,
+                ),
+        )
+        .is_equal_to(Damage::new(60.into()));
+    }
+
+    #[test]
+    fn sun_boosts_fire_and_weakens_water() {
+        let (attacker, defender) = (monster(), monster());
+        let field = Field::new().with_weather(Weather::Sun);
+        let fire = attack(Element::Fire, AttackCategory::Special);
+        let water = attack(Element::Water, AttackCategory::Special);
+        let pipeline = DamagePipeline::standard();
+
+        let fire_context =
+            DamageModifierContext::new(&attacker, &defender, &fire, &field, single(), single());
+        let water_context =
+            DamageModifierContext::new(&attacker, &defender, &water, &field, single(), single());
+
+        assert_that(&pipeline.apply(
+            Damage::new(20.into()),
+            &fire_context,
+            ArithmeticMode::Decimal,
+            None,
+        ))
+        .is_equal_to(Damage::new(30.into()));
+        assert_that(&pipeline.apply(
+            Damage::new(20.into()),
+            &water_context,
+            ArithmeticMode::Decimal,
+            None,
+        ))
+        .is_equal_to(Damage::new(10.into()));
+    }
+
+    #[test]
+    fn burn_halves_physical_damage() {
+        let mut attacker = monster();
+        attacker.inflict_status(StatusCondition::Burn);
+        let defender = monster();
+        let field = Field::new();
+        let physical = attack(Element::Normal, AttackCategory::Physical);
+        let special = attack(Element::Normal, AttackCategory::Special);
+        let pipeline = DamagePipeline::standard();
+
+        let physical_context =
+            DamageModifierContext::new(&attacker, &defender, &physical, &field, single(), single());
+        let special_context =
+            DamageModifierContext::new(&attacker, &defender, &special, &field, single(), single());
+
+        assert_that(&pipeline.apply(
+            Damage::new(20.into()),
+            &physical_context,
+            ArithmeticMode::Decimal,
+            None,
+        ))
+        .is_equal_to(Damage::new(10.into()));
+        assert_that(&pipeline.apply(
+            Damage::new(20.into()),
+            &special_context,
+            ArithmeticMode::Decimal,
+            None,
+        ))
+        .is_equal_to(Damage::new(20.into()));
+    }
+
+    #[test]
+    fn fixed_point_mode_chains_final_modifiers_before_applying_them() {
+        let mut attacker = monster();
+        attacker.give_held_item(Box::new(LifeOrb::new()));
+        let defender = monster();
+        let field = Field::new();
+        let attack = attack(Element::Normal, AttackCategory::Physical);
+        let context = DamageModifierContext::new(
+            &attacker,
+            &defender,
+            &attack,
+            &field,
+            DamageMultiplier::new(Decimal::new(15, 1)),
+            DamageMultiplier::new(2.into()),
+        );
+
+        assert_that(
+            &DamagePipeline::standard()
+                .with_modifier(Box::new(Screen))
+                .apply(
+                    Damage::new(37.into()),
+                    &context,
+                    ArithmeticMode::FixedPoint,
+                    Some(100),
+                ),
+        )
+        .is_equal_to(Damage::new(71.into()));
+    }
+
+    #[test]
+    fn fixed_point_mode_applies_random_roll_after_weather() {
+        let (attacker, defender) = (monster(), monster());
+        let field = Field::new();
+        let attack = attack(Element::Normal, AttackCategory::Physical);
+        let context =
+            DamageModifierContext::new(&attacker, &defender, &attack, &field, single(), single());
+
+        assert_that(
+            &DamagePipeline::standard()
+                .with_modifier(Box::new(FlatBonus))
+                .apply(
+                    Damage::new(37.into()),
+                    &context,
+                    ArithmeticMode::FixedPoint,
+                    Some(85),
+                ),
+        )
+        .is_equal_to(Damage::new(39.into()));
+    }
+}
@@ -0,0 +1,123 @@
+use rust_decimal::Decimal;
+
+use crate::combat::{
+    DamageModification, DamageModifier, DamageModifierContext, DamageMultiplier, ModifierStage,
+    Weather,
+};
+use crate::item::{DamageContext, HolderRole};
+use crate::monster::{AttackCategory, StatusCondition};
+use crate::Element;
+
+pub struct WeatherModifier;
+
+impl DamageModifier for WeatherModifier {
+    fn name(&self) -> &str {
+        "Weather"
+    }
+
+    fn stage(&self) -> ModifierStage {
+        ModifierStage::Weather
+    }
+
+    fn modify(&self, context: &DamageModifierContext) -> DamageModification {
+        let boosted = match (context.field().weather(), context.attack().element()) {
+            (Some(Weather::Sun), Element::Fire) | (Some(Weather::Rain), Element::Water) => true,
+            (Some(Weather::Sun), Element::Water) | (Some(Weather::Rain), Element::Fire) => false,
+            _ => return DamageModification::Unchanged,
+        };
+        if boosted {
+            return DamageModification::Multiplier(DamageMultiplier::new(Decimal::new(15, 1)));
+        }
+        DamageModification::Multiplier(DamageMultiplier::new(Decimal::new(5, 1)))
+    }
+}
+
+pub struct StabModifier;
+
+impl DamageModifier for StabModifier {
+    fn name(&self) -> &str {
+        "STAB"
+    }
+
+    fn stage(&self) -> ModifierStage {
+        ModifierStage::Stab
+    }
+
+    fn modify(&self, context: &DamageModifierContext) -> DamageModification {
+        DamageModification::Multiplier(context.stab_multiplier())
+    }
+}
+
+pub struct TypeEffectivenessModifier;
+
+impl DamageModifier for TypeEffectivenessModifier {
+    fn name(&self) -> &str {
+        "Type effectiveness"
+    }
+
+    fn stage(&self) -> ModifierStage {
+        ModifierStage::TypeEffectiveness
+    }
+
+    fn modify(&self, context: &DamageModifierContext) -> DamageModification {
+        DamageModification::Multiplier(context.type_effectiveness())
+    }
+}
+
+pub struct BurnModifier;
+
+impl DamageModifier for BurnModifier {
+    fn name(&self) -> &str {
+        "Burn"
+    }
+
+    fn stage(&self) -> ModifierStage {
+        ModifierStage::Burn
+    }
+
+    fn modify(&self, context: &DamageModifierContext) -> DamageModification {
+        if context.attacker().status() == Some(&StatusCondition::Burn)
+            && context.attack().category() == &AttackCategory::Physical
+        {
+            return DamageModification::Multiplier(DamageMultiplier::new(Decimal::new(5, 1)));
+        }
+        DamageModification::Unchanged
+    }
+}
+
+pub struct HeldItemModifier {
+    role: HolderRole,
+}
+
+impl HeldItemModifier {
+    pub fn new(role: HolderRole) -> Self {
+        HeldItemModifier { role }
+    }
+}
+
+impl DamageModifier for HeldItemModifier {
+    fn name(&self) -> &str {
+        match self.role {
+            HolderRole::Attacker => "Attacker held item",
+            HolderRole::Defender => "Defender held item",
+        }
+    }
+
+    fn stage(&self) -> ModifierStage {
+        ModifierStage::Final
+    }
+
+    fn modify(&self, context: &DamageModifierContext) -> DamageModification {
+        let holder = match self.role {
+            HolderRole::Attacker => context.attacker(),
+            HolderRole::Defender => context.defender(),
+        };
+        let type_effectiveness = context.type_effectiveness();
+        match holder.held_item() {
+            Some(item) => DamageModification::Multiplier(item.damage_multiplier(
+                &DamageContext::new(self.role, context.attack(), &type_effectiveness),
+            )),
+            None => DamageModification::Unchanged,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Weather {
+    Sun,
+    Rain,
+    Sandstorm,
+    Hail,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Field {
+    weather: Option<Weather>,
+}
+
+impl Field {
+    pub fn new() -> Self {
+        Field::default()
+    }
+
+    pub fn with_weather(mut self, weather: Weather) -> Self {
+        self.weather = Some(weather);
+        self
+    }
+
+    pub fn weather(&self) -> Option<Weather> {
+        self.weather
+    }
+
+    pub fn set_weather(&mut self, weather: Option<Weather>) {
+        self.weather = weather;
+    }
+}
@@ -1,7 +1,30 @@
+pub use arithmetic::ArithmeticMode;
+pub use battle_kind::BattleKind;
+pub use capture::{Ball, CaptureCalculator, CaptureError, CaptureOutcome};
+pub use combat_event::CombatEvent;
 pub use combat_service::*;
+pub use damage_formula::DamageFormula;
 pub use damage_multiplier::DamageMultiplier;
+pub(crate) use damage_multiplier::PrimitiveDamageMultiplier;
+pub use damage_pipeline::*;
+pub use field::{Field, Weather};
+pub use move_outcome::MoveOutcome;
+pub use random::*;
+pub use stab::*;
+pub use type_analysis::*;
 pub use type_effectiveness::*;
 
+mod arithmetic;
+mod battle_kind;
+mod capture;
+mod combat_event;
 mod combat_service;
+mod damage_formula;
 mod damage_multiplier;
+mod damage_pipeline;
+mod field;
+mod move_outcome;
+mod random;
+mod stab;
+mod type_analysis;
 mod type_effectiveness;
@@ -0,0 +1,56 @@
+use crate::combat::CombatEvent;
+use crate::monster::Damage;
+use crate::moves::MoveEffect;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct MoveOutcome {
+    hits: u8,
+    damage_dealt: Damage,
+    applied_effects: Vec<MoveEffect>,
+    events: Vec<CombatEvent>,
+}
+
+impl MoveOutcome {
+    pub fn hits(&self) -> u8 {
+        self.hits
+    }
+
+    pub fn damage_dealt(&self) -> &Damage {
+        &self.damage_dealt
+    }
+
+    pub fn applied_effects(&self) -> &[MoveEffect] {
+        &self.applied_effects
+    }
+
+    pub fn events(&self) -> &[CombatEvent] {
+        &self.events
+    }
+
+    pub(crate) fn record_hit(&mut self) {
+        self.hits += 1;
+    }
+
+    pub(crate) fn record_damage_dealt(&mut self, damage: Damage) {
+        self.damage_dealt = damage;
+    }
+
+    pub(crate) fn record_applied_effect(&mut self, effect: MoveEffect) {
+        self.applied_effects.push(effect);
+    }
+
+    pub(crate) fn record_event(&mut self, event: CombatEvent) {
+        self.events.push(event);
+    }
+}
+
+impl Default for MoveOutcome {
+    fn default() -> Self {
+        MoveOutcome {
+            hits: 0,
+            damage_dealt: Damage::new(0.into()),
+            applied_effects: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::cell::RefCell;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+#[cfg_attr(test, mockall::automock)]
+pub trait RandomNumberGenerator {
+    fn next_below(&self, upper_bound: u32) -> u32;
+}
+
+pub struct RandomNumberGeneratorImpl {
+    rng: RefCell<StdRng>,
+}
+
+impl RandomNumberGeneratorImpl {
+    pub fn new() -> Self {
+        RandomNumberGeneratorImpl {
+            rng: RefCell::new(StdRng::from_entropy()),
+        }
+    }
+
+    pub fn seeded(seed: u64) -> Self {
+        RandomNumberGeneratorImpl {
+            rng: RefCell::new(StdRng::seed_from_u64(seed)),
+        }
+    }
+}
+
+impl Default for RandomNumberGeneratorImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RandomNumberGenerator for RandomNumberGeneratorImpl {
+    fn next_below(&self, upper_bound: u32) -> u32 {
+        self.rng.borrow_mut().gen_range(0..upper_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn generates_numbers_below_upper_bound() {
+        let rng = RandomNumberGeneratorImpl::new();
+
+        (0..100).for_each(|_| assert_that(&rng.next_below(3)).is_less_than(3));
+    }
+
+    #[test]
+    fn seeded_generators_produce_same_sequence() {
+        let first = RandomNumberGeneratorImpl::seeded(7);
+        let second = RandomNumberGeneratorImpl::seeded(7);
+
+        (0..10).for_each(|_| {
+            assert_that(&first.next_below(1000)).is_equal_to(second.next_below(1000))
+        });
+    }
+}
@@ -0,0 +1,174 @@
+use rust_decimal::Decimal;
+
+use crate::combat::{DamageMultiplier, PrimitiveDamageMultiplier};
+use crate::monster::{Attack, Monster};
+
+const ADAPTABILITY: &str = "Adaptability";
+
+#[cfg_attr(test, mockall::automock)]
+pub trait StabCalculator {
+    fn calculate(&self, attacker: &Monster, attack: &Attack) -> DamageMultiplier;
+}
+
+pub struct StabCalculatorImpl {
+    multiplier: DamageMultiplier,
+    adaptability_multiplier: DamageMultiplier,
+    retains_base_types: bool,
+}
+
+impl StabCalculatorImpl {
+    pub fn new() -> Self {
+        StabCalculatorImpl {
+            multiplier: DamageMultiplier::new(Decimal::new(15, 1)),
+            adaptability_multiplier: DamageMultiplier::new(2.into()),
+            retains_base_types: false,
+        }
+    }
+
+    pub fn with_multiplier(mut self, multiplier: DamageMultiplier) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn with_adaptability_multiplier(mut self, multiplier: DamageMultiplier) -> Self {
+        self.adaptability_multiplier = multiplier;
+        self
+    }
+
+    pub fn retaining_base_types(mut self, retains_base_types: bool) -> Self {
+        self.retains_base_types = retains_base_types;
+        self
+    }
+
+    fn matches_attacker_type(&self, attacker: &Monster, attack: &Attack) -> bool {
+        attacker.monster_type().has_element(attack.element())
+            || (self.retains_base_types
+                && attacker.base_monster_type().has_element(attack.element()))
+    }
+}
+
+impl Default for StabCalculatorImpl {
+    fn default() -> Self {
+        StabCalculatorImpl::new()
+    }
+}
+
+impl StabCalculator for StabCalculatorImpl {
+    fn calculate(&self, attacker: &Monster, attack: &Attack) -> DamageMultiplier {
+        if !self.matches_attacker_type(attacker, attack) {
+            return PrimitiveDamageMultiplier::Single.into();
+        }
+        match attacker.ability() {
+            Some(ability) if ability.name() == ADAPTABILITY => self.adaptability_multiplier,
+            _ => self.multiplier,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use spectral::prelude::*;
+
+    use crate::monster::{
+        Ability, AttackCategory, AttackPower, Health, Level, MonsterBuilder, MonsterType, Stats,
+    };
+    use crate::species::Species;
+    use crate::Element;
+
+    use super::*;
+
+    fn attack(element: Element) -> Attack {
+        Attack::new(
+            element,
+            AttackCategory::Special,
+            AttackPower::new(90.into()),
+        )
+    }
+
+    fn monster(primary_element: Element, secondary_element: Option<Element>) -> Monster {
+        Monster::new(
+            MonsterType::new(primary_element, secondary_element),
+            Health::new(10.into()),
+        )
+    }
+
+    #[test]
+    fn grants_stab_for_primary_element() {
+        assert_that(
+            &StabCalculatorImpl::new()
+                .calculate(&monster(Element::Fire, None), &attack(Element::Fire)),
+        )
+        .is_equal_to(DamageMultiplier::new(Decimal::new(15, 1)));
+    }
+
+    #[test]
+    fn grants_stab_for_secondary_element() {
+        assert_that(&StabCalculatorImpl::new().calculate(
+            &monster(Element::Fire, Some(Element::Flying)),
+            &attack(Element::Flying),
+        ))
+        .is_equal_to(DamageMultiplier::new(Decimal::new(15, 1)));
+    }
+
+    #[test]
+    fn grants_no_stab_for_unmatched_element() {
+        assert_that(
+            &StabCalculatorImpl::new()
+                .calculate(&monster(Element::Fire, None), &attack(Element::Water)),
+        )
+        .is_equal_to(DamageMultiplier::new(1.into()));
+    }
+
+    #[test]
+    fn uses_configured_multiplier() {
+        assert_that(
+            &StabCalculatorImpl::new()
+                .with_multiplier(DamageMultiplier::new(Decimal::new(125, 2)))
+                .calculate(&monster(Element::Fire, None), &attack(Element::Fire)),
+        )
+        .is_equal_to(DamageMultiplier::new(Decimal::new(125, 2)));
+    }
+
+    #[test]
+    fn grants_stab_for_added_types() {
+        let mut attacker = monster(Element::Fire, None);
+        attacker.add_type(Element::Grass);
+
+        assert_that(&StabCalculatorImpl::new().calculate(&attacker, &attack(Element::Grass)))
+            .is_equal_to(DamageMultiplier::new(Decimal::new(15, 1)));
+    }
+
+    #[test]
+    fn retains_base_type_stab_after_type_replacement_when_configured() {
+        let mut attacker = monster(Element::Fire, None);
+        attacker.replace_type(MonsterType::new(Element::Water, None));
+
+        assert_that(&StabCalculatorImpl::new().calculate(&attacker, &attack(Element::Fire)))
+            .is_equal_to(DamageMultiplier::new(1.into()));
+        assert_that(
+            &StabCalculatorImpl::new()
+                .retaining_base_types(true)
+                .calculate(&attacker, &attack(Element::Fire)),
+        )
+        .is_equal_to(DamageMultiplier::new(Decimal::new(15, 1)));
+    }
+
+    #[test]
+    fn adaptability_doubles_stab() {
+        let species = Rc::new(Species::new(
+            "Test Species",
+            MonsterType::new(Element::Normal, None),
+            Stats::uniform(50),
+            Vec::new(),
+            vec![Ability::new(ADAPTABILITY)],
+        ));
+        let attacker = MonsterBuilder::new(species, Level::new(50))
+            .build()
+            .unwrap();
+
+        assert_that(&StabCalculatorImpl::new().calculate(&attacker, &attack(Element::Normal)))
+            .is_equal_to(DamageMultiplier::new(2.into()));
+    }
+}
@@ -0,0 +1,390 @@
+use rust_decimal::Decimal;
+
+use crate::combat::{
+    DamageMultiplier, RegistryTypeEffectivenessCalculator, TypeEffectivenessCalculator,
+};
+use crate::monster::MonsterType;
+use crate::{Element, ElementRegistry};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct DefensiveProfile {
+    monster_type: MonsterType,
+    multipliers: Vec<(Element, DamageMultiplier)>,
+}
+
+impl DefensiveProfile {
+    pub fn monster_type(&self) -> &MonsterType {
+        &self.monster_type
+    }
+
+    pub fn multipliers(&self) -> &[(Element, DamageMultiplier)] {
+        &self.multipliers
+    }
+
+    pub fn multiplier_against(&self, element: &Element) -> Option<DamageMultiplier> {
+        self.multipliers
+            .iter()
+            .find(|(candidate, _)| candidate == element)
+            .map(|(_, multiplier)| *multiplier)
+    }
+
+    pub fn weaknesses(&self) -> Vec<&Element> {
+        self.elements_where(|value| value > 1.into())
+    }
+
+    pub fn resistances(&self) -> Vec<&Element> {
+        self.elements_where(|value| value > 0.into() && value < 1.into())
+    }
+
+    pub fn immunities(&self) -> Vec<&Element> {
+        self.elements_where(|value| value == 0.into())
+    }
+
+    fn elements_where<F>(&self, predicate: F) -> Vec<&Element>
+    where
+        F: Fn(Decimal) -> bool,
+    {
+        self.multipliers
+            .iter()
+            .filter(|(_, multiplier)| predicate(multiplier.value()))
+            .map(|(element, _)| element)
+            .collect()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct CoverageEntry {
+    defender_type: MonsterType,
+    best_element: Option<Element>,
+    multiplier: DamageMultiplier,
+}
+
+impl CoverageEntry {
+    pub fn defender_type(&self) -> &MonsterType {
+        &self.defender_type
+    }
+
+    pub fn best_element(&self) -> Option<&Element> {
+        self.best_element.as_ref()
+    }
+
+    pub fn multiplier(&self) -> DamageMultiplier {
+        self.multiplier
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct OffensiveCoverage {
+    entries: Vec<CoverageEntry>,
+}
+
+impl OffensiveCoverage {
+    pub fn entries(&self) -> &[CoverageEntry] {
+        &self.entries
+    }
+
+    pub fn super_effective_against(&self) -> Vec<&MonsterType> {
+        self.defender_types_where(|value| value > 1.into())
+    }
+
+    pub fn neutral_against(&self) -> Vec<&MonsterType> {
+        self.defender_types_where(|value| value == 1.into())
+    }
+
+    pub fn resisted_by(&self) -> Vec<&MonsterType> {
+        self.defender_types_where(|value| value < 1.into())
+    }
+
+    fn defender_types_where<F>(&self, predicate: F) -> Vec<&MonsterType>
+    where
+        F: Fn(Decimal) -> bool,
+    {
+        self.entries
+            .iter()
+            .filter(|entry| predicate(entry.multiplier.value()))
+            .map(|entry| &entry.defender_type)
+            .collect()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct TeamTypeSummary {
+    profiles: Vec<DefensiveProfile>,
+    weakness_counts: Vec<(Element, usize)>,
+    unresisted_elements: Vec<Element>,
+}
+
+impl TeamTypeSummary {
+    pub fn profiles(&self) -> &[DefensiveProfile] {
+        &self.profiles
+    }
+
+    pub fn weakness_counts(&self) -> &[(Element, usize)] {
+        &self.weakness_counts
+    }
+
+    pub fn shared_weaknesses(&self) -> Vec<&Element> {
+        self.weakness_counts
+            .iter()
+            .filter(|(_, count)| *count > 1)
+            .map(|(element, _)| element)
+            .collect()
+    }
+
+    pub fn unresisted_elements(&self) -> &[Element] {
+        &self.unresisted_elements
+    }
+}
+
+pub struct TypeAnalyzer<TEC: TypeEffectivenessCalculator> {
+    type_effectiveness_calculator: TEC,
+    elements: Vec<Element>,
+}
+
+impl TypeAnalyzer<RegistryTypeEffectivenessCalculator> {
+    pub fn for_registry(registry: ElementRegistry) -> Self {
+        let elements = registry.elements().collect();
+        TypeAnalyzer::new(RegistryTypeEffectivenessCalculator::new(registry))
+            .with_elements(elements)
+    }
+}
+
+impl<TEC: TypeEffectivenessCalculator> TypeAnalyzer<TEC> {
+    pub fn new(type_effectiveness_calculator: TEC) -> Self {
+        TypeAnalyzer {
+            type_effectiveness_calculator,
+            elements: Element::ALL.to_vec(),
+        }
+    }
+
+    pub fn with_elements(mut self, elements: Vec<Element>) -> Self {
+        self.elements = elements;
+        self
+    }
+
+    pub fn elements(&self) -> &[Element] {
+        &self.elements
+    }
+
+    pub fn defensive_profile(&self, monster_type: &MonsterType) -> DefensiveProfile {
+        DefensiveProfile {
+            monster_type: monster_type.clone(),
+            multipliers: self
+                .elements
+                .iter()
+                .map(|element| {
+                    (
+                        *element,
+                        self.type_effectiveness_calculator
+                            .calculate(element, monster_type),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn offensive_coverage(&self, attack_elements: &[Element]) -> OffensiveCoverage {
+        OffensiveCoverage {
+            entries: monster_types_of(&self.elements)
+                .into_iter()
+                .map(|defender_type| self.coverage_entry(attack_elements, defender_type))
+                .collect(),
+        }
+    }
+
+    pub fn team_summary(&self, team: &[MonsterType]) -> TeamTypeSummary {
+        let profiles: Vec<DefensiveProfile> = team
+            .iter()
+            .map(|monster_type| self.defensive_profile(monster_type))
+            .collect();
+        let weakness_counts = self
+            .elements
+            .iter()
+            .map(|element| {
+                let count = profiles
+                    .iter()
+                    .filter(|profile| profile.weaknesses().contains(&element))
+                    .count();
+                (*element, count)
+            })
+            .collect();
+        let unresisted_elements = self
+            .elements
+            .iter()
+            .filter(|element| {
+                profiles.iter().all(|profile| {
+                    profile
+                        .multiplier_against(element)
+                        .is_none_or(|multiplier| multiplier.value() >= 1.into())
+                })
+            })
+            .cloned()
+            .collect();
+
+        TeamTypeSummary {
+            profiles,
+            weakness_counts,
+            unresisted_elements,
+        }
+    }
+
+    fn coverage_entry(
+        &self,
+        attack_elements: &[Element],
+        defender_type: MonsterType,
+    ) -> CoverageEntry {
+        let best = attack_elements
+            .iter()
+            .map(|element| {
+                (
+                    element,
+                    self.type_effectiveness_calculator
+                        .calculate(element, &defender_type),
+                )
+            })
+            .fold(
+                None,
+                |best: Option<(&Element, DamageMultiplier)>, candidate| match best {
+                    Some(current) if current.1.value() >= candidate.1.value() => Some(current),
+                    _ => Some(candidate),
+                },
+            );
+
+        match best {
+            Some((element, multiplier)) => CoverageEntry {
+                defender_type,
+                best_element: Some(*element),
+                multiplier,
+            },
+            None => CoverageEntry {
+                defender_type,
+                best_element: None,
+                multiplier: DamageMultiplier::new(0.into()),
+            },
+        }
+    }
+}
+
+pub fn all_monster_types() -> Vec<MonsterType> {
+    monster_types_of(&Element::ALL)
+}
+
+pub fn monster_types_of(elements: &[Element]) -> Vec<MonsterType> {
+    let mut monster_types = Vec::new();
+    for (index, primary_element) in elements.iter().enumerate() {
+        monster_types.push(MonsterType::new(*primary_element, None));
+        for secondary_element in elements.iter().skip(index + 1) {
+            monster_types.push(MonsterType::new(*primary_element, Some(*secondary_element)));
+        }
+    }
+    monster_types
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::combat::TypeEffectivenessCalculatorImpl;
+
+    use super::*;
+
+    fn under_test() -> TypeAnalyzer<TypeEffectivenessCalculatorImpl> {
+        TypeAnalyzer::new(TypeEffectivenessCalculatorImpl::new())
+    }
+
+    #[test]
+    fn enumerates_all_single_and_dual_types() {
+        assert_that(&all_monster_types().len()).is_equal_to(171);
+    }
+
+    #[test]
+    fn builds_defensive_profile_across_all_elements() {
+        let profile = under_test()
+            .defensive_profile(&MonsterType::new(Element::Water, Some(Element::Ground)));
+
+        assert_that(&profile.multipliers().len()).is_equal_to(18);
+        assert_that(&profile.weaknesses()).is_equal_to(vec![&Element::Grass]);
+        assert_that(&profile.multiplier_against(&Element::Grass))
+            .is_equal_to(Some(DamageMultiplier::new(4.into())));
+        assert_that(&profile.immunities()).is_equal_to(vec![&Element::Electric]);
+        assert_that(&profile.resistances()).is_equal_to(vec![
+            &Element::Fire,
+            &Element::Poison,
+            &Element::Rock,
+            &Element::Steel,
+        ]);
+    }
+
+    #[test]
+    fn picks_best_attack_element_for_each_defender_type() {
+        let coverage = under_test().offensive_coverage(&[Element::Ice, Element::Ground]);
+
+        let dragon_ground = coverage
+            .entries()
+            .iter()
+            .find(|entry| {
+                entry.defender_type() == &MonsterType::new(Element::Dragon, Some(Element::Ground))
+            })
+            .unwrap();
+        assert_that(&dragon_ground.best_element()).is_equal_to(Some(&Element::Ice));
+        assert_that(&dragon_ground.multiplier()).is_equal_to(DamageMultiplier::new(4.into()));
+    }
+
+    #[test]
+    fn reports_types_resisting_all_attack_elements() {
+        let coverage = under_test().offensive_coverage(&[Element::Normal]);
+
+        assert_that(
+            &coverage
+                .resisted_by()
+                .contains(&&MonsterType::new(Element::Ghost, None)),
+        )
+        .is_true();
+        assert_that(&coverage.super_effective_against().is_empty()).is_true();
+    }
+
+    #[test]
+    fn coverage_without_attack_elements_has_no_best_element() {
+        let coverage = under_test().offensive_coverage(&[]);
+
+        assert_that(&coverage.entries()[0].best_element()).is_none();
+        assert_that(&coverage.entries()[0].multiplier())
+            .is_equal_to(DamageMultiplier::new(Decimal::from(0)));
+    }
+
+    #[test]
+    fn analyses_elements_registered_at_runtime() {
+        let mut registry = ElementRegistry::standard();
+        let light = registry.register("Light", "#FFFFE0").unwrap();
+        registry
+            .set_effectiveness(light, Element::Ghost, DamageMultiplier::new(2.into()))
+            .unwrap();
+        let analyzer = TypeAnalyzer::for_registry(registry);
+
+        let profile = analyzer.defensive_profile(&MonsterType::new(Element::Ghost, None));
+        let coverage = analyzer.offensive_coverage(&[light]);
+
+        assert_that(&profile.multipliers().len()).is_equal_to(19);
+        assert_that(&profile.weaknesses().contains(&&light)).is_true();
+        assert_that(&coverage.entries().len()).is_equal_to(190);
+        assert_that(
+            &coverage
+                .neutral_against()
+                .contains(&&MonsterType::new(light, None)),
+        )
+        .is_true();
+    }
+
+    #[test]
+    fn summarises_shared_weaknesses_and_unresisted_elements() {
+        let summary = under_test().team_summary(&[
+            MonsterType::new(Element::Fire, None),
+            MonsterType::new(Element::Rock, None),
+        ]);
+
+        assert_that(&summary.shared_weaknesses())
+            .is_equal_to(vec![&Element::Ground, &Element::Water]);
+        assert_that(&summary.unresisted_elements().contains(&Element::Electric)).is_true();
+        assert_that(&summary.unresisted_elements().contains(&Element::Fire)).is_false();
+    }
+}
@@ -1,10 +1,12 @@
 use primitive_multipliers::*;
+pub use registry::RegistryTypeEffectivenessCalculator;
 
-use crate::combat::DamageMultiplier;
+use crate::combat::{DamageMultiplier, PrimitiveDamageMultiplier};
 use crate::monster::MonsterType;
 use crate::Element;
 
 mod primitive_multipliers;
+mod registry;
 
 #[cfg_attr(test, mockall::automock)]
 pub trait TypeEffectivenessCalculator {
@@ -19,7 +21,10 @@ impl TypeEffectivenessCalculatorImpl {
         TypeEffectivenessCalculatorImpl
     }
 
-    fn primitive_multiplier(attack_type: &Element, defender_type: &Element) -> DamageMultiplier {
+    pub(crate) fn primitive_multiplier(
+        attack_type: &Element,
+        defender_type: &Element,
+    ) -> DamageMultiplier {
         match attack_type {
             Element::Bug => bug_damage_multiplier(defender_type),
             Element::Dark => dark_damage_multiplier(defender_type),
@@ -39,6 +44,7 @@ impl TypeEffectivenessCalculatorImpl {
             Element::Rock => rock_damage_multiplier(defender_type),
             Element::Steel => steel_damage_multiplier(defender_type),
             Element::Water => water_damage_multiplier(defender_type),
+            Element::Custom(_) => PrimitiveDamageMultiplier::Single,
         }
         .into()
     }
@@ -46,16 +52,12 @@ impl TypeEffectivenessCalculatorImpl {
 
 impl TypeEffectivenessCalculator for TypeEffectivenessCalculatorImpl {
     fn calculate(&self, attack_type: &Element, defender_type: &MonsterType) -> DamageMultiplier {
-        let first_multiplier =
-            Self::primitive_multiplier(attack_type, defender_type.primary_element());
-
-        match defender_type.secondary_element() {
-            Some(element) => {
-                let second_multiplier = Self::primitive_multiplier(attack_type, element);
-                first_multiplier.combined_with(second_multiplier)
-            }
-            None => first_multiplier,
-        }
+        defender_type.elements().iter().fold(
+            PrimitiveDamageMultiplier::Single.into(),
+            |multiplier: DamageMultiplier, element| {
+                multiplier.combined_with(Self::primitive_multiplier(attack_type, element))
+            },
+        )
     }
 }
 
@@ -64,18 +66,47 @@ mod tests {
     use rust_decimal::Decimal;
     use spectral::prelude::*;
 
+    use crate::ElementId;
+
     use super::*;
 
     fn under_test() -> TypeEffectivenessCalculatorImpl {
         TypeEffectivenessCalculatorImpl::new()
     }
 
+    #[test]
+    fn treats_custom_elements_as_neutral() {
+        let custom = Element::Custom(ElementId::new(0));
+
+        assert_that(&under_test().calculate(&custom, &MonsterType::new(Element::Ghost, None)))
+            .is_equal_to(DamageMultiplier::new(1.into()));
+        assert_that(&under_test().calculate(&Element::Fire, &MonsterType::new(custom, None)))
+            .is_equal_to(DamageMultiplier::new(1.into()));
+    }
+
+    #[test]
+    fn typeless_defender_takes_neutral_damage() {
+        assert_that(&under_test().calculate(&Element::Fighting, &MonsterType::typeless()))
+            .is_equal_to(DamageMultiplier::new(1.into()));
+    }
+
+    #[test]
+    fn combines_multipliers_of_added_third_element() {
+        let monster_type = MonsterType::new(Element::Water, Some(Element::Ground))
+            .with_added_element(Element::Grass);
+
+        assert_that(&under_test().calculate(&Element::Fire, &monster_type))
+            .is_equal_to(DamageMultiplier::new(1.into()));
+        assert_that(&under_test().calculate(&Element::Ice, &monster_type))
+            .is_equal_to(DamageMultiplier::new(2.into()));
+    }
+
     #[test]
     fn normal_has_multiplier_1_against_normal() {
         assert_that(
             &under_test().calculate(&Element::Normal, &MonsterType::new(Element::Normal, None)),
         )
-        .is_equal_to(&DamageMultiplier::new(1.into()));
+        .is_equal_to(DamageMultiplier::new(1.into()));
     }
 
     #[test]
@@ -83,7 +114,7 @@ mod tests {
         assert_that(
             &under_test().calculate(&Element::Fire, &MonsterType::new(Element::Water, None)),
         )
-        .is_equal_to(&DamageMultiplier::new(Decimal::new(5, 1)));
+        .is_equal_to(DamageMultiplier::new(Decimal::new(5, 1)));
     }
 
     #[test]
@@ -91,7 +122,7 @@ mod tests {
         assert_that(
             &under_test().calculate(&Element::Grass, &MonsterType::new(Element::Water, None)),
         )
-        .is_equal_to(&DamageMultiplier::new(2.into()));
+        .is_equal_to(DamageMultiplier::new(2.into()));
     }
 
     #[test]
@@ -100,6 +131,6 @@ mod tests {
             &Element::Fire,
             &MonsterType::new(Element::Water, Some(Element::Fire)),
         ))
-        .is_equal_to(&DamageMultiplier::new(Decimal::new(25, 2)));
+        .is_equal_to(DamageMultiplier::new(Decimal::new(25, 2)));
     }
 }
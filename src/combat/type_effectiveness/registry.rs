@@ -0,0 +1,69 @@
+use crate::combat::{DamageMultiplier, PrimitiveDamageMultiplier, TypeEffectivenessCalculator};
+use crate::monster::MonsterType;
+use crate::{Element, ElementRegistry};
+
+pub struct RegistryTypeEffectivenessCalculator {
+    registry: ElementRegistry,
+}
+
+impl RegistryTypeEffectivenessCalculator {
+    pub fn new(registry: ElementRegistry) -> Self {
+        RegistryTypeEffectivenessCalculator { registry }
+    }
+
+    pub fn registry(&self) -> &ElementRegistry {
+        &self.registry
+    }
+}
+
+impl TypeEffectivenessCalculator for RegistryTypeEffectivenessCalculator {
+    fn calculate(&self, attack_type: &Element, defender_type: &MonsterType) -> DamageMultiplier {
+        defender_type.elements().iter().fold(
+            PrimitiveDamageMultiplier::Single.into(),
+            |multiplier: DamageMultiplier, element| {
+                multiplier.combined_with(self.registry.effectiveness(attack_type, element))
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::combat::TypeEffectivenessCalculatorImpl;
+
+    use super::*;
+
+    #[test]
+    fn matches_built_in_calculator_for_standard_registry() {
+        let under_test = RegistryTypeEffectivenessCalculator::new(ElementRegistry::standard());
+        let built_in = TypeEffectivenessCalculatorImpl::new();
+
+        for attack_element in Element::iter() {
+            let monster_type = MonsterType::new(Element::Water, Some(Element::Ground));
+            assert_that(&under_test.calculate(&attack_element, &monster_type))
+                .is_equal_to(built_in.calculate(&attack_element, &monster_type));
+        }
+    }
+
+    #[test]
+    fn combines_custom_element_multipliers_for_dual_types() {
+        let mut registry = ElementRegistry::standard();
+        let light = registry.register("Light", "#FFFFE0").unwrap();
+        registry
+            .set_effectiveness(light, Element::Ghost, DamageMultiplier::new(2.into()))
+            .unwrap();
+        registry
+            .set_effectiveness(light, Element::Dark, DamageMultiplier::new(2.into()))
+            .unwrap();
+
+        assert_that(
+            &RegistryTypeEffectivenessCalculator::new(registry).calculate(
+                &light,
+                &MonsterType::new(Element::Ghost, Some(Element::Dark)),
+            ),
+        )
+        .is_equal_to(DamageMultiplier::new(4.into()));
+    }
+}
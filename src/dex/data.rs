@@ -0,0 +1,174 @@
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct DexData {
+    #[serde(default)]
+    pub(crate) abilities: Vec<AbilityData>,
+    #[serde(default)]
+    pub(crate) items: Vec<ItemData>,
+    #[serde(default)]
+    pub(crate) moves: Vec<MoveData>,
+    #[serde(default)]
+    pub(crate) species: Vec<SpeciesData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct AbilityData {
+    pub(crate) id: u16,
+    pub(crate) name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ItemData {
+    pub(crate) id: u16,
+    pub(crate) name: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct MoveData {
+    pub(crate) id: u16,
+    pub(crate) name: String,
+    pub(crate) element: String,
+    pub(crate) category: String,
+    #[serde(default)]
+    pub(crate) power: Option<u32>,
+    pub(crate) pp: u8,
+    #[serde(default)]
+    pub(crate) effects: Vec<MoveEffectData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub(crate) enum MoveEffectData {
+    InflictStatus {
+        status: StatusConditionData,
+        #[serde(default)]
+        chance: Option<u8>,
+    },
+    Flinch {
+        chance: u8,
+    },
+    StatChange {
+        target: EffectTargetData,
+        stat: BattleStatData,
+        stages: i8,
+        #[serde(default)]
+        chance: Option<u8>,
+    },
+    Recoil(FractionData),
+    SelfDamage(FractionData),
+    Drain(FractionData),
+    Heal(FractionData),
+    MultiHit {
+        hits: HitCountData,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct FractionData {
+    pub(crate) numerator: u32,
+    pub(crate) denominator: u32,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum StatusConditionData {
+    Burn,
+    Freeze,
+    Paralysis,
+    Poison,
+    BadPoison,
+    Sleep,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum EffectTargetData {
+    User,
+    Target,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum BattleStatData {
+    Attack,
+    Defense,
+    SpecialAttack,
+    SpecialDefense,
+    Speed,
+    Accuracy,
+    Evasion,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum HitCountData {
+    Fixed(u8),
+    Range(HitRangeData),
+}
+
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum HitRangeData {
+    TwoToFive,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SpeciesData {
+    pub(crate) id: u16,
+    pub(crate) name: String,
+    pub(crate) types: Vec<String>,
+    pub(crate) base_stats: BaseStatsData,
+    pub(crate) abilities: Vec<u16>,
+    #[serde(default)]
+    pub(crate) moves: Vec<u16>,
+    #[serde(default)]
+    pub(crate) level_up_moves: Vec<LevelUpMoveData>,
+    #[serde(default)]
+    pub(crate) evolutions: Vec<EvolutionData>,
+    #[serde(default)]
+    pub(crate) growth_rate: Option<String>,
+    #[serde(default)]
+    pub(crate) catch_rate: Option<u8>,
+    #[serde(default)]
+    pub(crate) base_experience_yield: Option<u16>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct BaseStatsData {
+    pub(crate) hp: u16,
+    pub(crate) attack: u16,
+    pub(crate) defense: u16,
+    pub(crate) special_attack: u16,
+    pub(crate) special_defense: u16,
+    pub(crate) speed: u16,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct LevelUpMoveData {
+    pub(crate) level: u8,
+    #[serde(rename = "move")]
+    pub(crate) move_id: u16,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct EvolutionData {
+    pub(crate) into: u16,
+    #[serde(default)]
+    pub(crate) level: Option<u8>,
+    #[serde(default)]
+    pub(crate) item: Option<u16>,
+    #[serde(default)]
+    pub(crate) friendship: Option<u8>,
+    #[serde(default)]
+    pub(crate) trade: bool,
+}
@@ -0,0 +1,118 @@
+use std::rc::Rc;
+
+use crate::dex::Dex;
+
+const EMBEDDED_DEX: &str = include_str!("../../data/embedded_dex.json");
+
+pub mod ids {
+    include!(concat!(env!("OUT_DIR"), "/embedded_dex_ids.rs"));
+}
+
+thread_local! {
+    static EMBEDDED: Rc<Dex> =
+        Rc::new(Dex::from_json(EMBEDDED_DEX).expect("embedded dex data should be valid"));
+}
+
+impl Dex {
+    /// Parses the embedded data once per thread and shares it afterwards.
+    pub fn embedded() -> Rc<Dex> {
+        EMBEDDED.with(Rc::clone)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::monster::{Level, MonsterBuilder};
+    use crate::progression::EvolutionCondition;
+    use crate::species::SpeciesLookup;
+
+    use super::*;
+
+    #[test]
+    fn loads_the_embedded_dex() {
+        let dex = Dex::embedded();
+
+        assert_that(&dex.all_species().count()).is_equal_to(11);
+        assert_that(&dex.moves().count()).is_equal_to(18);
+    }
+
+    #[test]
+    fn typed_identifiers_match_the_data() {
+        let dex = Dex::embedded();
+
+        assert_that(
+            &dex.species(ids::species::PIKACHU)
+                .map(|species| species.name().to_string()),
+        )
+        .is_equal_to(Some("Pikachu".to_string()));
+        assert_that(
+            &dex.move_with_id(ids::moves::THUNDERBOLT)
+                .map(|move_definition| move_definition.name()),
+        )
+        .is_equal_to(Some("Thunderbolt"));
+        assert_that(
+            &dex.ability(ids::abilities::LIGHTNING_ROD)
+                .map(|ability| ability.name()),
+        )
+        .is_equal_to(Some("Lightning Rod"));
+        assert_that(
+            &dex.item(ids::items::CHOICE_SCARF)
+                .map(|item| item.name().to_string()),
+        )
+        .is_equal_to(Some("Choice Scarf".to_string()));
+    }
+
+    #[test]
+    fn every_species_has_an_identifier() {
+        let dex = Dex::embedded();
+
+        for species in dex.all_species() {
+            assert_that(&dex.species_with_id(species.id())).is_equal_to(Some(species.clone()));
+        }
+    }
+
+    #[test]
+    fn builds_monsters_from_embedded_species() {
+        let dex = Dex::embedded();
+        let charmander = dex.species(ids::species::CHARMANDER).unwrap();
+
+        let monster = MonsterBuilder::new(charmander.clone(), Level::new(5))
+            .build()
+            .unwrap();
+
+        assert_that(&monster.moveset().slots().len()).is_equal_to(3);
+        assert_that(&charmander.evolutions()[0].conditions().to_vec())
+            .is_equal_to(vec![EvolutionCondition::MinimumLevel(Level::new(16))]);
+    }
+
+    #[test]
+    fn resolves_evolution_items() {
+        let dex = Dex::embedded();
+        let pikachu = dex.species(ids::species::PIKACHU).unwrap();
+
+        assert_that(&pikachu.evolutions()[0].conditions().to_vec()).is_equal_to(vec![
+            EvolutionCondition::UseItem("Thunder Stone".to_string()),
+        ]);
+        assert_that(&dex.item_name(ids::items::THUNDER_STONE)).is_equal_to(Some("Thunder Stone"));
+    }
+
+    #[test]
+    fn every_item_identifier_resolves_to_a_name() {
+        let dex = Dex::embedded();
+
+        for id in [
+            ids::items::CHOICE_BAND,
+            ids::items::LEFTOVERS,
+            ids::items::THUNDER_STONE,
+        ] {
+            assert_that(&dex.item_name(id).is_some()).is_true();
+        }
+    }
+
+    #[test]
+    fn shares_the_parsed_dex() {
+        assert_that(&Rc::ptr_eq(&Dex::embedded(), &Dex::embedded())).is_true();
+    }
+}
@@ -0,0 +1,905 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::item::{held_item_id, held_item_named, held_item_with_id, HeldItem, ItemId};
+use crate::monster::{
+    Ability, AbilityId, AttackCategory, AttackPower, BattleStat, Level, MonsterType, Stats,
+    StatusCondition,
+};
+use crate::moves::{
+    Chance, EffectTarget, HitCount, MoveDamage, MoveDefinition, MoveEffect, MoveId,
+};
+use crate::progression::{Evolution, EvolutionCondition, GrowthRate};
+use crate::species::{Species, SpeciesId, SpeciesLookup};
+use crate::{Element, Fraction};
+
+#[cfg(feature = "embedded-dex")]
+pub use embedded::ids;
+
+use data::{
+    BattleStatData, DexData, EffectTargetData, EvolutionData, FractionData, HitCountData,
+    HitRangeData, MoveData, MoveEffectData, SpeciesData, StatusConditionData,
+};
+
+mod data;
+#[cfg(feature = "embedded-dex")]
+mod embedded;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum DataFormat {
+    Json,
+    Toml,
+    Ron,
+}
+
+impl DataFormat {
+    pub fn from_path(path: &Path) -> Option<DataFormat> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "json" => Some(DataFormat::Json),
+            "toml" => Some(DataFormat::Toml),
+            "ron" => Some(DataFormat::Ron),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum EntryKind {
+    Species,
+    Move,
+    Ability,
+    Item,
+}
+
+impl fmt::Display for EntryKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EntryKind::Species => "Species",
+            EntryKind::Move => "Move",
+            EntryKind::Ability => "Ability",
+            EntryKind::Item => "Item",
+        };
+        formatter.write_str(name)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Dex {
+    species: BTreeMap<SpeciesId, Rc<Species>>,
+    moves: BTreeMap<MoveId, MoveDefinition>,
+    abilities: BTreeMap<AbilityId, Ability>,
+    items: BTreeMap<ItemId, String>,
+}
+
+impl Dex {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, DexError> {
+        let path = path.as_ref();
+        let format = DataFormat::from_path(path)
+            .ok_or_else(|| DexError::UnsupportedFormat(path.to_path_buf()))?;
+        let text = fs::read_to_string(path).map_err(|source| DexError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&text, format)
+    }
+
+    pub fn parse(text: &str, format: DataFormat) -> Result<Self, DexError> {
+        match format {
+            DataFormat::Json => Self::from_json(text),
+            DataFormat::Toml => Self::from_toml(text),
+            DataFormat::Ron => Self::from_ron(text),
+        }
+    }
+
+    pub fn from_json(text: &str) -> Result<Self, DexError> {
+        Self::from_data(serde_json::from_str(text)?)
+    }
+
+    pub fn from_toml(text: &str) -> Result<Self, DexError> {
+        Self::from_data(toml::from_str(text)?)
+    }
+
+    pub fn from_ron(text: &str) -> Result<Self, DexError> {
+        Self::from_data(ron::from_str(text)?)
+    }
+
+    fn from_data(data: DexData) -> Result<Self, DexError> {
+        let mut dex = Dex::default();
+
+        for ability in data.abilities {
+            check_id(EntryKind::Ability, ability.id)?;
+            let id = AbilityId::new(ability.id);
+            if dex.abilities.contains_key(&id) {
+                return Err(DexError::DuplicateId(EntryKind::Ability, ability.id));
+            }
+            dex.abilities
+                .insert(id, Ability::new(&ability.name).with_id(id));
+        }
+        for item in data.items {
+            check_id(EntryKind::Item, item.id)?;
+            let id = ItemId::new(item.id);
+            if dex.items.contains_key(&id) {
+                return Err(DexError::DuplicateId(EntryKind::Item, item.id));
+            }
+            if let Some(expected) = held_item_named(&item.name)
+                .as_deref()
+                .and_then(held_item_id)
+                .filter(|expected| *expected != id)
+            {
+                return Err(DexError::ItemIdMismatch {
+                    item: item.name,
+                    id: item.id,
+                    expected: expected.value(),
+                });
+            }
+            if let Some(held_item) = held_item_with_id(id)
+                .filter(|held_item| !held_item.name().eq_ignore_ascii_case(item.name.trim()))
+            {
+                return Err(DexError::ReservedItemId {
+                    item: item.name,
+                    id: item.id,
+                    reserved_for: held_item.name().to_string(),
+                });
+            }
+            dex.items.insert(id, item.name);
+        }
+        for move_data in &data.moves {
+            check_id(EntryKind::Move, move_data.id)?;
+            let id = MoveId::new(move_data.id);
+            if dex.moves.contains_key(&id) {
+                return Err(DexError::DuplicateId(EntryKind::Move, move_data.id));
+            }
+            dex.moves.insert(id, resolve_move(move_data)?);
+        }
+
+        let mut resolver = SpeciesResolver {
+            data: BTreeMap::new(),
+            moves: &dex.moves,
+            abilities: &dex.abilities,
+            items: &dex.items,
+            resolved: BTreeMap::new(),
+            resolving: Vec::new(),
+        };
+        for species in &data.species {
+            check_id(EntryKind::Species, species.id)?;
+            if resolver.data.insert(species.id, species).is_some() {
+                return Err(DexError::DuplicateId(EntryKind::Species, species.id));
+            }
+        }
+        for species in &data.species {
+            resolver.resolve(species.id, None)?;
+        }
+        dex.species = resolver.resolved;
+
+        Ok(dex)
+    }
+
+    pub fn species(&self, id: SpeciesId) -> Option<Rc<Species>> {
+        self.species.get(&id).cloned()
+    }
+
+    pub fn all_species(&self) -> impl Iterator<Item = &Rc<Species>> {
+        self.species.values()
+    }
+
+    pub fn move_with_id(&self, id: MoveId) -> Option<&MoveDefinition> {
+        self.moves.get(&id)
+    }
+
+    pub fn move_named(&self, name: &str) -> Option<&MoveDefinition> {
+        self.moves
+            .values()
+            .find(|move_definition| move_definition.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = &MoveDefinition> {
+        self.moves.values()
+    }
+
+    pub fn ability(&self, id: AbilityId) -> Option<&Ability> {
+        self.abilities.get(&id)
+    }
+
+    pub fn ability_named(&self, name: &str) -> Option<&Ability> {
+        self.abilities
+            .values()
+            .find(|ability| ability.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    pub fn item_name(&self, id: ItemId) -> Option<&str> {
+        self.items.get(&id).map(String::as_str)
+    }
+
+    pub fn item(&self, id: ItemId) -> Option<Box<dyn HeldItem>> {
+        self.items.get(&id).and_then(|_| held_item_with_id(id))
+    }
+
+    pub fn item_named(&self, name: &str) -> Option<Box<dyn HeldItem>> {
+        self.items
+            .iter()
+            .find(|(_, item)| item.eq_ignore_ascii_case(name.trim()))
+            .and_then(|(id, _)| held_item_with_id(*id))
+    }
+}
+
+impl SpeciesLookup for Dex {
+    fn species_named(&self, name: &str) -> Option<Rc<Species>> {
+        self.species
+            .values()
+            .find(|species| species.name().eq_ignore_ascii_case(name.trim()))
+            .cloned()
+    }
+
+    fn species_with_id(&self, id: SpeciesId) -> Option<Rc<Species>> {
+        self.species(id)
+    }
+}
+
+fn check_id(kind: EntryKind, id: u16) -> Result<(), DexError> {
+    if id == 0 {
+        return Err(DexError::ReservedId(kind));
+    }
+    Ok(())
+}
+
+fn parse_level(species: &str, level: u8) -> Result<Level, DexError> {
+    if level < 1 || level > Level::MAX.value() {
+        return Err(DexError::InvalidLevel {
+            species: species.to_string(),
+            level,
+        });
+    }
+    Ok(Level::new(level))
+}
+
+fn parse_element(entry: &str, element: &str) -> Result<Element, DexError> {
+    element.parse().map_err(|_| DexError::UnknownElement {
+        entry: entry.to_string(),
+        element: element.to_string(),
+    })
+}
+
+fn resolve_move(data: &MoveData) -> Result<MoveDefinition, DexError> {
+    let category = match data.category.to_ascii_lowercase().as_str() {
+        "physical" => AttackCategory::Physical,
+        "special" => AttackCategory::Special,
+        "status" => AttackCategory::Status,
+        _ => {
+            return Err(DexError::UnknownCategory {
+                move_name: data.name.clone(),
+                category: data.category.clone(),
+            })
+        }
+    };
+    let damage = match data.power {
+        Some(power) => MoveDamage::Power(AttackPower::new(power.into())),
+        None => MoveDamage::None,
+    };
+
+    Ok(MoveDefinition::new(
+        &data.name,
+        parse_element(&data.name, &data.element)?,
+        category,
+        damage,
+        data.pp,
+        data.effects
+            .iter()
+            .map(|effect| resolve_effect(&data.name, effect))
+            .collect::<Result<Vec<_>, _>>()?,
+    )
+    .with_id(MoveId::new(data.id)))
+}
+
+fn resolve_effect(move_name: &str, data: &MoveEffectData) -> Result<MoveEffect, DexError> {
+    let chance = |chance: Option<u8>| match chance {
+        None => Ok(Chance::always()),
+        Some(chance) if chance <= 100 => Ok(Chance::percent(chance)),
+        Some(chance) => Err(DexError::InvalidChance {
+            move_name: move_name.to_string(),
+            chance,
+        }),
+    };
+    let fraction = |data: &FractionData| {
+        Fraction::checked_new(data.numerator, data.denominator).ok_or_else(|| {
+            DexError::ZeroDenominator {
+                move_name: move_name.to_string(),
+            }
+        })
+    };
+
+    Ok(match data {
+        MoveEffectData::InflictStatus { status, chance: c } => MoveEffect::InflictStatus {
+            status: match status {
+                StatusConditionData::Burn => StatusCondition::Burn,
+                StatusConditionData::Freeze => StatusCondition::Freeze,
+                StatusConditionData::Paralysis => StatusCondition::Paralysis,
+                StatusConditionData::Poison => StatusCondition::Poison,
+                StatusConditionData::BadPoison => StatusCondition::BadPoison,
+                StatusConditionData::Sleep => StatusCondition::Sleep,
+            },
+            chance: chance(*c)?,
+        },
+        MoveEffectData::Flinch { chance: c } => MoveEffect::Flinch {
+            chance: chance(Some(*c))?,
+        },
+        MoveEffectData::StatChange {
+            target,
+            stat,
+            stages,
+            chance: c,
+        } => MoveEffect::StatChange {
+            target: match target {
+                EffectTargetData::User => EffectTarget::User,
+                EffectTargetData::Target => EffectTarget::Target,
+            },
+            stat: match stat {
+                BattleStatData::Attack => BattleStat::Attack,
+                BattleStatData::Defense => BattleStat::Defense,
+                BattleStatData::SpecialAttack => BattleStat::SpecialAttack,
+                BattleStatData::SpecialDefense => BattleStat::SpecialDefense,
+                BattleStatData::Speed => BattleStat::Speed,
+                BattleStatData::Accuracy => BattleStat::Accuracy,
+                BattleStatData::Evasion => BattleStat::Evasion,
+            },
+            stages: *stages,
+            chance: chance(*c)?,
+        },
+        MoveEffectData::Recoil(data) => MoveEffect::Recoil(fraction(data)?),
+        MoveEffectData::SelfDamage(data) => MoveEffect::SelfDamage(fraction(data)?),
+        MoveEffectData::Drain(data) => MoveEffect::Drain(fraction(data)?),
+        MoveEffectData::Heal(data) => MoveEffect::Heal(fraction(data)?),
+        MoveEffectData::MultiHit { hits } => MoveEffect::MultiHit(match hits {
+            HitCountData::Fixed(hits) => HitCount::Fixed(*hits),
+            HitCountData::Range(HitRangeData::TwoToFive) => HitCount::TwoToFive,
+        }),
+    })
+}
+
+fn parse_growth_rate(species: &str, growth_rate: &str) -> Result<GrowthRate, DexError> {
+    let normalised: String = growth_rate
+        .chars()
+        .filter(|character| character.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    match normalised.as_str() {
+        "fast" => Ok(GrowthRate::Fast),
+        "mediumfast" => Ok(GrowthRate::MediumFast),
+        "mediumslow" => Ok(GrowthRate::MediumSlow),
+        "slow" => Ok(GrowthRate::Slow),
+        "erratic" => Ok(GrowthRate::Erratic),
+        "fluctuating" => Ok(GrowthRate::Fluctuating),
+        _ => Err(DexError::UnknownGrowthRate {
+            species: species.to_string(),
+            growth_rate: growth_rate.to_string(),
+        }),
+    }
+}
+
+struct SpeciesResolver<'a> {
+    data: BTreeMap<u16, &'a SpeciesData>,
+    moves: &'a BTreeMap<MoveId, MoveDefinition>,
+    abilities: &'a BTreeMap<AbilityId, Ability>,
+    items: &'a BTreeMap<ItemId, String>,
+    resolved: BTreeMap<SpeciesId, Rc<Species>>,
+    resolving: Vec<u16>,
+}
+
+impl<'a> SpeciesResolver<'a> {
+    fn resolve(&mut self, id: u16, referenced_by: Option<&str>) -> Result<Rc<Species>, DexError> {
+        if let Some(species) = self.resolved.get(&SpeciesId::new(id)) {
+            return Ok(species.clone());
+        }
+        let data = match self.data.get(&id) {
+            Some(data) => *data,
+            None => {
+                return Err(DexError::DanglingReference {
+                    from: referenced_by.unwrap_or_default().to_string(),
+                    kind: EntryKind::Species,
+                    id,
+                })
+            }
+        };
+        if let Some(start) = self.resolving.iter().position(|resolving| *resolving == id) {
+            let cycle = self.resolving[start..]
+                .iter()
+                .map(|species| self.data[species].name.as_str())
+                .chain(std::iter::once(data.name.as_str()))
+                .collect::<Vec<_>>();
+            return Err(DexError::EvolutionCycle(cycle.join(" -> ")));
+        }
+
+        self.resolving.push(id);
+        let evolutions = data
+            .evolutions
+            .iter()
+            .map(|evolution| self.resolve_evolution(data, evolution))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.resolving.pop();
+
+        let species = Rc::new(self.resolve_species(data)?.with_evolutions(evolutions));
+        self.resolved.insert(SpeciesId::new(id), species.clone());
+        Ok(species)
+    }
+
+    fn resolve_evolution(
+        &mut self,
+        data: &SpeciesData,
+        evolution: &EvolutionData,
+    ) -> Result<Evolution, DexError> {
+        let into = self.resolve(evolution.into, Some(&data.name))?;
+        let mut conditions = Vec::new();
+        if let Some(level) = evolution.level {
+            conditions.push(EvolutionCondition::MinimumLevel(parse_level(
+                &data.name, level,
+            )?));
+        }
+        if let Some(item) = evolution.item {
+            let name =
+                self.items
+                    .get(&ItemId::new(item))
+                    .ok_or_else(|| DexError::DanglingReference {
+                        from: data.name.clone(),
+                        kind: EntryKind::Item,
+                        id: item,
+                    })?;
+            conditions.push(EvolutionCondition::UseItem(name.clone()));
+        }
+        if let Some(friendship) = evolution.friendship {
+            conditions.push(EvolutionCondition::MinimumFriendship(friendship));
+        }
+        if evolution.trade {
+            conditions.push(EvolutionCondition::Trade);
+        }
+        Ok(Evolution::new(into, conditions))
+    }
+
+    fn resolve_species(&self, data: &SpeciesData) -> Result<Species, DexError> {
+        let elements = data
+            .types
+            .iter()
+            .map(|element| parse_element(&data.name, element))
+            .collect::<Result<Vec<_>, _>>()?;
+        let monster_type = match elements.as_slice() {
+            [] => MonsterType::typeless(),
+            [primary] => MonsterType::new(*primary, None),
+            [primary, secondary] => MonsterType::new(*primary, Some(*secondary)),
+            _ => return Err(DexError::TooManyTypes(data.name.clone())),
+        };
+        let abilities = data
+            .abilities
+            .iter()
+            .map(|id| {
+                self.abilities
+                    .get(&AbilityId::new(*id))
+                    .cloned()
+                    .ok_or_else(|| DexError::DanglingReference {
+                        from: data.name.clone(),
+                        kind: EntryKind::Ability,
+                        id: *id,
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let learnable_moves = data
+            .moves
+            .iter()
+            .map(|id| self.resolve_move(data, *id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let level_up_moves = data
+            .level_up_moves
+            .iter()
+            .map(|level_up_move| {
+                Ok((
+                    parse_level(&data.name, level_up_move.level)?,
+                    self.resolve_move(data, level_up_move.move_id)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, DexError>>()?;
+        let base_stats = &data.base_stats;
+
+        let mut species = Species::new(
+            &data.name,
+            monster_type,
+            Stats::new(
+                base_stats.hp,
+                base_stats.attack,
+                base_stats.defense,
+                base_stats.special_attack,
+                base_stats.special_defense,
+                base_stats.speed,
+            ),
+            learnable_moves,
+            abilities,
+        )
+        .with_id(SpeciesId::new(data.id))
+        .with_level_up_moves(level_up_moves);
+        if let Some(growth_rate) = &data.growth_rate {
+            species = species.with_growth_rate(parse_growth_rate(&data.name, growth_rate)?);
+        }
+        if let Some(catch_rate) = data.catch_rate {
+            species = species.with_catch_rate(catch_rate);
+        }
+        if let Some(base_experience_yield) = data.base_experience_yield {
+            species = species.with_base_experience_yield(base_experience_yield);
+        }
+        Ok(species)
+    }
+
+    fn resolve_move(&self, data: &SpeciesData, id: u16) -> Result<MoveDefinition, DexError> {
+        self.moves
+            .get(&MoveId::new(id))
+            .cloned()
+            .ok_or_else(|| DexError::DanglingReference {
+                from: data.name.clone(),
+                kind: EntryKind::Move,
+                id,
+            })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DexError {
+    #[error("Could not read {path}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("Data file {0} is not JSON, TOML or RON")]
+    UnsupportedFormat(PathBuf),
+    #[error("Invalid JSON dex data: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid TOML dex data: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("Invalid RON dex data: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("{0} ID {1} is defined more than once")]
+    DuplicateId(EntryKind, u16),
+    #[error("{0} ID 0 is reserved for unassigned entries")]
+    ReservedId(EntryKind),
+    #[error("{from} refers to {kind} ID {id}, which is not defined")]
+    DanglingReference {
+        from: String,
+        kind: EntryKind,
+        id: u16,
+    },
+    #[error("{entry} has unknown element {element}")]
+    UnknownElement { entry: String, element: String },
+    #[error("Move {move_name} has unknown category {category}")]
+    UnknownCategory { move_name: String, category: String },
+    #[error("Species {species} has unknown growth rate {growth_rate}")]
+    UnknownGrowthRate {
+        species: String,
+        growth_rate: String,
+    },
+    #[error("Move {move_name} has an effect with chance {chance}, above 100 percent")]
+    InvalidChance { move_name: String, chance: u8 },
+    #[error("Move {move_name} has an effect with a zero denominator")]
+    ZeroDenominator { move_name: String },
+    #[error("Species {0} has more than two types")]
+    TooManyTypes(String),
+    #[error("Item {item} must use ID {expected}, not {id}")]
+    ItemIdMismatch {
+        item: String,
+        id: u16,
+        expected: u16,
+    },
+    #[error("Item {item} cannot use ID {id}, which belongs to {reserved_for}")]
+    ReservedItemId {
+        item: String,
+        id: u16,
+        reserved_for: String,
+    },
+    #[error("Species {species} uses level {level}, which is out of range")]
+    InvalidLevel { species: String, level: u8 },
+    #[error("Species evolve in a cycle: {0}")]
+    EvolutionCycle(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    const SAMPLE_DEX: &str = include_str!("../../data/sample_dex.json");
+
+    fn sample_dex() -> Dex {
+        Dex::from_json(SAMPLE_DEX).unwrap()
+    }
+
+    #[test]
+    fn loads_the_sample_dex() {
+        let dex = sample_dex();
+
+        assert_that(&dex.all_species().count()).is_equal_to(5);
+        assert_that(&dex.moves().count()).is_equal_to(8);
+        assert_that(&dex.ability(AbilityId::new(1)))
+            .is_equal_to(Some(&Ability::new("Overgrow").with_id(AbilityId::new(1))));
+        assert_that(&dex.item(ItemId::new(5)).map(|item| item.name().to_string()))
+            .is_equal_to(Some("Leftovers".to_string()));
+    }
+
+    #[test]
+    fn loads_data_files_by_extension() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("data/sample_dex.json");
+
+        assert_that(&Dex::load(path).map(|dex| dex.all_species().count()).ok())
+            .is_equal_to(Some(5));
+    }
+
+    #[test]
+    fn looks_up_entries_by_name_and_id() {
+        let dex = sample_dex();
+        let sproutle = dex.species_named("sproutle").unwrap();
+
+        assert_that(&sproutle.id()).is_equal_to(SpeciesId::new(1));
+        assert_that(&dex.species(SpeciesId::new(1))).is_equal_to(Some(sproutle));
+        assert_that(&dex.move_named("Vine Whip").map(MoveDefinition::id))
+            .is_equal_to(Some(MoveId::new(2)));
+        assert_that(&dex.move_with_id(MoveId::new(2)).map(MoveDefinition::name))
+            .is_equal_to(Some("Vine Whip"));
+        assert_that(&dex.ability_named("static").is_some()).is_true();
+        assert_that(&dex.item_named("Choice Scarf").is_some()).is_true();
+        assert_that(&dex.species_named("Missingno").is_none()).is_true();
+    }
+
+    #[test]
+    fn resolves_species_references() {
+        let dex = sample_dex();
+        let sproutle = dex.species_named("Sproutle").unwrap();
+        let evolution = &sproutle.evolutions()[0];
+
+        assert_that(&evolution.into_species().name()).is_equal_to("Bloomtree");
+        assert_that(&evolution.conditions().to_vec())
+            .is_equal_to(vec![EvolutionCondition::MinimumLevel(Level::new(16))]);
+        assert_that(&sproutle.monster_type())
+            .is_equal_to(&MonsterType::new(Element::Grass, Some(Element::Poison)));
+        assert_that(&sproutle.abilities().to_vec())
+            .is_equal_to(vec![Ability::new("Overgrow").with_id(AbilityId::new(1))]);
+        assert_that(&sproutle.level_up_moves().len()).is_equal_to(2);
+        assert_that(&sproutle.growth_rate()).is_equal_to(GrowthRate::MediumSlow);
+    }
+
+    #[test]
+    fn loads_toml_and_ron() {
+        let toml = r#"
+            [[moves]]
+            id = 1
+            name = "Tackle"
+            element = "Normal"
+            category = "Physical"
+            power = 40
+            pp = 35
+
+            [[species]]
+            id = 1
+            name = "Pebblet"
+            types = ["Rock"]
+            abilities = []
+            moves = [1]
+            base_stats = { hp = 40, attack = 80, defense = 100, special_attack = 30, special_defense = 30, speed = 20 }
+        "#;
+        let ron = r#"(
+            moves: [(id: 1, name: "Tackle", element: "Normal", category: "Physical", power: Some(40), pp: 35)],
+            species: [(
+                id: 1,
+                name: "Pebblet",
+                types: ["Rock"],
+                abilities: [],
+                moves: [1],
+                base_stats: (hp: 40, attack: 80, defense: 100, special_attack: 30, special_defense: 30, speed: 20),
+            )],
+        )"#;
+
+        let from_toml = Dex::from_toml(toml).unwrap();
+        let from_ron = Dex::from_ron(ron).unwrap();
+
+        assert_that(&from_toml.species(SpeciesId::new(1)))
+            .is_equal_to(from_ron.species(SpeciesId::new(1)));
+        assert_that(&from_toml.move_named("Tackle").is_some()).is_true();
+    }
+
+    #[test]
+    fn reports_dangling_references() {
+        let json = r#"{
+            "species": [{
+                "id": 1,
+                "name": "Pebblet",
+                "types": ["Rock"],
+                "abilities": [],
+                "moves": [7],
+                "base_stats": { "hp": 40, "attack": 80, "defense": 100, "special_attack": 30, "special_defense": 30, "speed": 20 }
+            }]
+        }"#;
+
+        let error = Dex::from_json(json).unwrap_err();
+
+        assert_that(&error.to_string())
+            .is_equal_to("Pebblet refers to Move ID 7, which is not defined".to_string());
+    }
+
+    #[test]
+    fn reports_dangling_evolution_targets_and_cycles() {
+        let species = |id: u16, into: u16| {
+            format!(
+                r#"{{ "id": {}, "name": "Species {}", "types": [], "abilities": [],
+                    "evolutions": [{{ "into": {}, "level": 10 }}],
+                    "base_stats": {{ "hp": 1, "attack": 1, "defense": 1, "special_attack": 1, "special_defense": 1, "speed": 1 }} }}"#,
+                id, id, into
+            )
+        };
+        let dangling = format!(r#"{{ "species": [{}] }}"#, species(1, 9));
+        let cycle = format!(
+            r#"{{ "species": [{}, {}, {}] }}"#,
+            species(1, 2),
+            species(2, 3),
+            species(3, 1)
+        );
+
+        assert_that(&matches!(
+            Dex::from_json(&dangling),
+            Err(DexError::DanglingReference {
+                kind: EntryKind::Species,
+                id: 9,
+                ..
+            })
+        ))
+        .is_true();
+        assert_that(&Dex::from_json(&cycle).unwrap_err().to_string()).is_equal_to(
+            "Species evolve in a cycle: Species 1 -> Species 2 -> Species 3 -> Species 1"
+                .to_string(),
+        );
+    }
+
+    #[test]
+    fn resolves_evolution_items_by_id() {
+        let species = |item: u16| {
+            format!(
+                r#"{{ "items": [{{ "id": 100, "name": "Thunder Stone" }}], "species": [
+                    {{ "id": 1, "name": "Sparkmouse", "types": [], "abilities": [],
+                      "evolutions": [{{ "into": 2, "item": {} }}],
+                      "base_stats": {{ "hp": 1, "attack": 1, "defense": 1, "special_attack": 1, "special_defense": 1, "speed": 1 }} }},
+                    {{ "id": 2, "name": "Voltmouse", "types": [], "abilities": [],
+                      "base_stats": {{ "hp": 1, "attack": 1, "defense": 1, "special_attack": 1, "special_defense": 1, "speed": 1 }} }}
+                ] }}"#,
+                item
+            )
+        };
+
+        let dex = Dex::from_json(&species(100)).unwrap();
+
+        assert_that(
+            &dex.species(SpeciesId::new(1)).unwrap().evolutions()[0]
+                .conditions()
+                .to_vec(),
+        )
+        .is_equal_to(vec![EvolutionCondition::UseItem(
+            "Thunder Stone".to_string(),
+        )]);
+        assert_that(&dex.item(ItemId::new(100)).is_none()).is_true();
+        assert_that(&dex.item_name(ItemId::new(100))).is_equal_to(Some("Thunder Stone"));
+        assert_that(&matches!(
+            Dex::from_json(&species(101)),
+            Err(DexError::DanglingReference {
+                kind: EntryKind::Item,
+                id: 101,
+                ..
+            })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn loads_move_effects() {
+        let dex = sample_dex();
+        let recoil = r#"
+            [[moves]]
+            id = 1
+            name = "Take Down"
+            element = "Normal"
+            category = "Physical"
+            power = 90
+            pp = 20
+            effects = [
+                { kind = "recoil", numerator = 1, denominator = 4 },
+                { kind = "multi_hit", hits = "two_to_five" },
+            ]
+        "#;
+        let zero_denominator = r#"{ "moves": [{ "id": 1, "name": "Absorb", "element": "Grass", "category": "Special", "power": 20, "pp": 25,
+            "effects": [{ "kind": "drain", "numerator": 1, "denominator": 0 }] }] }"#;
+
+        assert_that(&dex.move_named("Growl").unwrap().effects().to_vec()).is_equal_to(vec![
+            MoveEffect::StatChange {
+                target: EffectTarget::Target,
+                stat: BattleStat::Attack,
+                stages: -1,
+                chance: Chance::always(),
+            },
+        ]);
+        assert_that(&dex.move_named("Flamethrower").unwrap().effects().to_vec()).is_equal_to(vec![
+            MoveEffect::InflictStatus {
+                status: StatusCondition::Burn,
+                chance: Chance::percent(10),
+            },
+        ]);
+        assert_that(
+            &Dex::from_toml(recoil)
+                .unwrap()
+                .move_named("Take Down")
+                .unwrap()
+                .effects()
+                .to_vec(),
+        )
+        .is_equal_to(vec![
+            MoveEffect::Recoil(Fraction::new(1, 4)),
+            MoveEffect::MultiHit(HitCount::TwoToFive),
+        ]);
+        assert_that(&matches!(
+            Dex::from_json(zero_denominator),
+            Err(DexError::ZeroDenominator { .. })
+        ))
+        .is_true();
+    }
+
+    #[test]
+    fn reports_invalid_entries() {
+        let duplicate =
+            r#"{ "abilities": [{ "id": 1, "name": "Static" }, { "id": 1, "name": "Blaze" }] }"#;
+        let element = r#"{ "moves": [{ "id": 1, "name": "Zap", "element": "Plasma", "category": "Special", "pp": 5 }] }"#;
+        let unassigned = r#"{ "abilities": [{ "id": 0, "name": "Static" }] }"#;
+        let mismatched = r#"{ "items": [{ "id": 1, "name": "Leftovers" }] }"#;
+        let reserved = r#"{ "items": [{ "id": 5, "name": "Rare Candy" }] }"#;
+        let level = r#"{ "moves": [{ "id": 1, "name": "Tackle", "element": "Normal", "category": "Physical", "power": 40, "pp": 35 }],
+            "species": [{ "id": 1, "name": "Pebblet", "types": [], "abilities": [],
+                "level_up_moves": [{ "level": 101, "move": 1 }],
+                "base_stats": { "hp": 1, "attack": 1, "defense": 1, "special_attack": 1, "special_defense": 1, "speed": 1 } }] }"#;
+
+        assert_that(&matches!(
+            Dex::from_json(duplicate),
+            Err(DexError::DuplicateId(EntryKind::Ability, 1))
+        ))
+        .is_true();
+        assert_that(&matches!(
+            Dex::from_json(element),
+            Err(DexError::UnknownElement { .. })
+        ))
+        .is_true();
+        assert_that(&matches!(
+            Dex::from_json(unassigned),
+            Err(DexError::ReservedId(EntryKind::Ability))
+        ))
+        .is_true();
+        assert_that(&matches!(
+            Dex::from_json(mismatched),
+            Err(DexError::ItemIdMismatch {
+                id: 1,
+                expected: 5,
+                ..
+            })
+        ))
+        .is_true();
+        assert_that(&matches!(
+            Dex::from_json(reserved),
+            Err(DexError::ReservedItemId { id: 5, .. })
+        ))
+        .is_true();
+        assert_that(&matches!(
+            Dex::from_json(level),
+            Err(DexError::InvalidLevel { level: 101, .. })
+        ))
+        .is_true();
+        assert_that(&matches!(
+            Dex::from_json("{ \"pokemon\": [] }"),
+            Err(DexError::Json(_))
+        ))
+        .is_true();
+        assert_that(&matches!(
+            Dex::load("dex.yaml"),
+            Err(DexError::UnsupportedFormat(_))
+        ))
+        .is_true();
+    }
+}
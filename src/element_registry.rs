@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::combat::{DamageMultiplier, PrimitiveDamageMultiplier, TypeEffectivenessCalculatorImpl};
+use crate::{Element, ElementId};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ElementDefinition {
+    element: Element,
+    name: String,
+    colour: String,
+}
+
+impl ElementDefinition {
+    pub fn element(&self) -> Element {
+        self.element
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn colour(&self) -> &str {
+        &self.colour
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ElementRegistry {
+    definitions: Vec<ElementDefinition>,
+    chart: HashMap<(Element, Element), DamageMultiplier>,
+    next_custom_id: u32,
+}
+
+impl ElementRegistry {
+    pub fn new() -> Self {
+        ElementRegistry::default()
+    }
+
+    pub fn standard() -> Self {
+        let mut registry = ElementRegistry::new();
+        for element in Element::iter() {
+            registry.definitions.push(ElementDefinition {
+                element,
+                name: element.name().to_string(),
+                colour: element.colour().to_string(),
+            });
+        }
+        for attack_element in Element::iter() {
+            for defender_element in Element::iter() {
+                let multiplier = TypeEffectivenessCalculatorImpl::primitive_multiplier(
+                    &attack_element,
+                    &defender_element,
+                );
+                if multiplier != PrimitiveDamageMultiplier::Single.into() {
+                    registry
+                        .chart
+                        .insert((attack_element, defender_element), multiplier);
+                }
+            }
+        }
+        registry
+    }
+
+    pub fn register(&mut self, name: &str, colour: &str) -> Result<Element, ElementRegistryError> {
+        if self.parse(name).is_ok() {
+            return Err(ElementRegistryError::DuplicateElementName(name.to_string()));
+        }
+
+        let id = u16::try_from(self.next_custom_id)
+            .map_err(|_| ElementRegistryError::TooManyCustomElements)?;
+        self.next_custom_id += 1;
+        let element = Element::Custom(ElementId::new(id));
+        self.definitions.push(ElementDefinition {
+            element,
+            name: name.to_string(),
+            colour: colour.to_string(),
+        });
+        Ok(element)
+    }
+
+    pub fn set_effectiveness(
+        &mut self,
+        attack_element: Element,
+        defender_element: Element,
+        multiplier: DamageMultiplier,
+    ) -> Result<(), ElementRegistryError> {
+        for element in [attack_element, defender_element] {
+            if self.definition(&element).is_none() {
+                return Err(ElementRegistryError::UnknownElement(element));
+            }
+        }
+
+        self.chart
+            .insert((attack_element, defender_element), multiplier);
+        Ok(())
+    }
+
+    pub fn effectiveness(
+        &self,
+        attack_element: &Element,
+        defender_element: &Element,
+    ) -> DamageMultiplier {
+        self.chart
+            .get(&(*attack_element, *defender_element))
+            .copied()
+            .unwrap_or_else(|| PrimitiveDamageMultiplier::Single.into())
+    }
+
+    pub fn definition(&self, element: &Element) -> Option<&ElementDefinition> {
+        self.definitions
+            .iter()
+            .find(|definition| &definition.element == element)
+    }
+
+    pub fn name(&self, element: &Element) -> Option<&str> {
+        self.definition(element).map(|definition| definition.name())
+    }
+
+    pub fn display(&self, element: Element) -> ElementDisplay<'_> {
+        ElementDisplay {
+            registry: self,
+            element,
+        }
+    }
+
+    pub fn definitions(&self) -> &[ElementDefinition] {
+        &self.definitions
+    }
+
+    pub fn elements(&self) -> impl Iterator<Item = Element> + '_ {
+        self.definitions.iter().map(|definition| definition.element)
+    }
+
+    pub fn parse(&self, name: &str) -> Result<Element, ElementRegistryError> {
+        self.definitions
+            .iter()
+            .find(|definition| definition.name.eq_ignore_ascii_case(name.trim()))
+            .map(|definition| definition.element)
+            .ok_or_else(|| ElementRegistryError::UnknownElementName(name.to_string()))
+    }
+}
+
+pub struct ElementDisplay<'a> {
+    registry: &'a ElementRegistry,
+    element: Element,
+}
+
+impl fmt::Display for ElementDisplay<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.registry.name(&self.element) {
+            Some(name) => formatter.write_str(name),
+            None => write!(formatter, "{}", self.element),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ElementRegistryError {
+    #[error("Element name is already registered: {0}")]
+    DuplicateElementName(String),
+    #[error("Element is not registered: {0}")]
+    UnknownElement(Element),
+    #[error("Unknown element name: {0}")]
+    UnknownElementName(String),
+    #[error("No more custom element ids are available")]
+    TooManyCustomElements,
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn standard_registry_contains_built_in_elements() {
+        let registry = ElementRegistry::standard();
+
+        assert_that(&registry.elements().count()).is_equal_to(18);
+        assert_that(&registry.effectiveness(&Element::Water, &Element::Fire))
+            .is_equal_to(DamageMultiplier::new(2.into()));
+        assert_that(&registry.effectiveness(&Element::Normal, &Element::Ghost))
+            .is_equal_to(DamageMultiplier::new(0.into()));
+    }
+
+    #[test]
+    fn registers_custom_elements_with_distinct_ids() {
+        let mut registry = ElementRegistry::standard();
+
+        let light = registry.register("Light", "#FFFFE0").unwrap();
+        let sound = registry.register("Sound", "#C0C0FF").unwrap();
+
+        assert_that(&light.is_custom()).is_true();
+        assert_that(&light).is_not_equal_to(sound);
+        assert_that(&registry.definition(&sound).unwrap().name()).is_equal_to("Sound");
+        assert_that(&registry.parse("light")).is_equal_to(Ok(light));
+    }
+
+    #[test]
+    fn assigns_the_same_ids_to_the_same_registrations() {
+        let mut first = ElementRegistry::standard();
+        let mut second = ElementRegistry::standard();
+        first.register("Sound", "#C0C0FF").unwrap();
+
+        let light = first.register("Light", "#FFFFE0").unwrap();
+        second.register("Sound", "#C0C0FF").unwrap();
+
+        assert_that(&second.register("Light", "#FFFFE0")).is_equal_to(Ok(light));
+        assert_that(&light).is_equal_to(Element::Custom(ElementId::new(1)));
+    }
+
+    #[test]
+    fn displays_elements_by_registered_name() {
+        let mut registry = ElementRegistry::standard();
+        let light = registry.register("Light", "#FFFFE0").unwrap();
+
+        assert_that(&registry.name(&light)).is_equal_to(Some("Light"));
+        assert_that(&registry.display(light).to_string()).is_equal_to("Light".to_string());
+        assert_that(&registry.display(Element::Fire).to_string()).is_equal_to("Fire".to_string());
+        assert_that(&ElementRegistry::standard().display(light).to_string())
+            .is_equal_to(light.to_string());
+    }
+
+    #[test]
+    fn rejects_duplicate_element_names() {
+        let mut registry = ElementRegistry::standard();
+
+        assert_that(&registry.register("fire", "#FF0000")).is_equal_to(Err(
+            ElementRegistryError::DuplicateElementName("fire".to_string()),
+        ));
+    }
+
+    #[test]
+    fn sets_effectiveness_between_registered_elements() {
+        let mut registry = ElementRegistry::standard();
+        let cosmic = registry.register("Cosmic", "#2E1A47").unwrap();
+
+        registry
+            .set_effectiveness(cosmic, Element::Dragon, DamageMultiplier::new(2.into()))
+            .unwrap();
+        registry
+            .set_effectiveness(
+                Element::Dark,
+                cosmic,
+                DamageMultiplier::new(Decimal::new(5, 1)),
+            )
+            .unwrap();
+
+        assert_that(&registry.effectiveness(&cosmic, &Element::Dragon))
+            .is_equal_to(DamageMultiplier::new(2.into()));
+        assert_that(&registry.effectiveness(&Element::Dark, &cosmic))
+            .is_equal_to(DamageMultiplier::new(Decimal::new(5, 1)));
+        assert_that(&registry.effectiveness(&cosmic, &Element::Fire))
+            .is_equal_to(DamageMultiplier::new(1.into()));
+    }
+
+    #[test]
+    fn fails_to_set_effectiveness_for_unregistered_element() {
+        let mut registry = ElementRegistry::standard();
+        let unregistered = Element::Custom(ElementId::new(u16::MAX));
+
+        assert_that(&registry.set_effectiveness(
+            unregistered,
+            Element::Fire,
+            DamageMultiplier::new(2.into()),
+        ))
+        .is_equal_to(Err(ElementRegistryError::UnknownElement(unregistered)));
+    }
+}
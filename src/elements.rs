@@ -1,4 +1,7 @@
-#[derive(Debug, Eq, PartialEq)]
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, PartialOrd, Ord)]
 pub enum Element {
     Bug,
     Dark,
@@ -18,4 +21,202 @@ pub enum Element {
     Rock,
     Steel,
     Water,
+    Custom(ElementId),
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub struct ElementId(u16);
+
+impl ElementId {
+    pub fn new(value: u16) -> Self {
+        ElementId(value)
+    }
+
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+impl Element {
+    pub const ALL: [Element; 18] = [
+        Element::Bug,
+        Element::Dark,
+        Element::Dragon,
+        Element::Electric,
+        Element::Fairy,
+        Element::Fighting,
+        Element::Fire,
+        Element::Flying,
+        Element::Ghost,
+        Element::Grass,
+        Element::Ground,
+        Element::Ice,
+        Element::Normal,
+        Element::Poison,
+        Element::Psychic,
+        Element::Rock,
+        Element::Steel,
+        Element::Water,
+    ];
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, Element::Custom(_))
+    }
+
+    pub fn iter() -> impl Iterator<Item = Element> {
+        Element::ALL.iter().copied()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Element::Bug => "Bug",
+            Element::Dark => "Dark",
+            Element::Dragon => "Dragon",
+            Element::Electric => "Electric",
+            Element::Fairy => "Fairy",
+            Element::Fighting => "Fighting",
+            Element::Fire => "Fire",
+            Element::Flying => "Flying",
+            Element::Ghost => "Ghost",
+            Element::Grass => "Grass",
+            Element::Ground => "Ground",
+            Element::Ice => "Ice",
+            Element::Normal => "Normal",
+            Element::Poison => "Poison",
+            Element::Psychic => "Psychic",
+            Element::Rock => "Rock",
+            Element::Steel => "Steel",
+            Element::Water => "Water",
+            Element::Custom(_) => "Custom",
+        }
+    }
+
+    pub fn colour(&self) -> &'static str {
+        match self {
+            Element::Bug => "#A6B91A",
+            Element::Dark => "#705746",
+            Element::Dragon => "#6F35FC",
+            Element::Electric => "#F7D02C",
+            Element::Fairy => "#D685AD",
+            Element::Fighting => "#C22E28",
+            Element::Fire => "#EE8130",
+            Element::Flying => "#A98FF3",
+            Element::Ghost => "#735797",
+            Element::Grass => "#7AC74C",
+            Element::Ground => "#E2BF65",
+            Element::Ice => "#96D9D6",
+            Element::Normal => "#A8A77A",
+            Element::Poison => "#A33EA1",
+            Element::Psychic => "#F95587",
+            Element::Rock => "#B6A136",
+            Element::Steel => "#B7B7CE",
+            Element::Water => "#6390F0",
+            Element::Custom(_) => "#68A090",
+        }
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Element::Custom(id) => write!(formatter, "Custom({})", id.value()),
+            element => formatter.write_str(element.name()),
+        }
+    }
+}
+
+impl FromStr for Element {
+    type Err = ParseElementError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let trimmed = value.trim();
+        if let Some(id) = trimmed
+            .strip_prefix("Custom(")
+            .and_then(|rest| rest.strip_suffix(')'))
+        {
+            return id
+                .parse()
+                .map(|id| Element::Custom(ElementId::new(id)))
+                .map_err(|_| ParseElementError::UnknownElement(value.to_string()));
+        }
+        Element::iter()
+            .find(|element| element.name().eq_ignore_ascii_case(trimmed))
+            .ok_or_else(|| ParseElementError::UnknownElement(value.to_string()))
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ParseElementError {
+    #[error("Unknown element: {0}")]
+    UnknownElement(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn iterates_all_distinct_elements() {
+        let elements: HashSet<Element> = Element::iter().collect();
+
+        assert_that(&elements.len()).is_equal_to(18);
+    }
+
+    #[test]
+    fn parses_element_ignoring_case() {
+        assert_that(&"fire".parse::<Element>()).is_equal_to(Ok(Element::Fire));
+        assert_that(&" Psychic ".parse::<Element>()).is_equal_to(Ok(Element::Psychic));
+    }
+
+    #[test]
+    fn fails_to_parse_unknown_element() {
+        assert_that(&"Sound".parse::<Element>())
+            .is_equal_to(Err(ParseElementError::UnknownElement("Sound".to_string())));
+    }
+
+    #[test]
+    fn displays_element_name() {
+        assert_that(&Element::Electric.to_string()).is_equal_to("Electric".to_string());
+    }
+
+    #[test]
+    fn display_round_trips_through_parsing() {
+        for element in Element::iter() {
+            assert_that(&element.to_string().parse::<Element>()).is_equal_to(Ok(element));
+        }
+    }
+
+    #[test]
+    fn displays_custom_element_id() {
+        assert_that(&Element::Custom(ElementId::new(3)).to_string())
+            .is_equal_to("Custom(3)".to_string());
+    }
+
+    #[test]
+    fn parses_displayed_custom_element_id() {
+        assert_that(&"Custom(3)".parse::<Element>())
+            .is_equal_to(Ok(Element::Custom(ElementId::new(3))));
+        assert_that(&"Custom(x)".parse::<Element>()).is_equal_to(Err(
+            ParseElementError::UnknownElement("Custom(x)".to_string()),
+        ));
+    }
+
+    #[test]
+    fn custom_elements_are_not_built_in() {
+        assert_that(&Element::iter().any(|element| element.is_custom())).is_false();
+    }
+
+    #[test]
+    fn provides_colour_code() {
+        assert_that(&Element::Water.colour()).is_equal_to("#6390F0");
+    }
+
+    #[test]
+    fn orders_elements_alphabetically() {
+        assert_that(&(Element::Bug < Element::Water)).is_true();
+    }
 }
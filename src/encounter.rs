@@ -0,0 +1,222 @@
+use std::rc::Rc;
+
+use crate::combat::RandomNumberGenerator;
+use crate::monster::{
+    IndividualValues, Level, Monster, MonsterBuilder, MonsterBuilderError, Nature, Stat, Stats,
+};
+use crate::species::Species;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct EncounterEntry {
+    species: Rc<Species>,
+    minimum_level: Level,
+    maximum_level: Level,
+    weight: u32,
+}
+
+impl EncounterEntry {
+    pub fn new(
+        species: Rc<Species>,
+        minimum_level: Level,
+        maximum_level: Level,
+        weight: u32,
+    ) -> Result<Self, EncounterError> {
+        if minimum_level > maximum_level {
+            return Err(EncounterError::InvalidLevelRange(
+                minimum_level.value(),
+                maximum_level.value(),
+            ));
+        }
+        if weight == 0 {
+            return Err(EncounterError::ZeroWeight(species.name().to_string()));
+        }
+        Ok(EncounterEntry {
+            species,
+            minimum_level,
+            maximum_level,
+            weight,
+        })
+    }
+
+    pub fn species(&self) -> &Rc<Species> {
+        &self.species
+    }
+
+    pub fn minimum_level(&self) -> Level {
+        self.minimum_level
+    }
+
+    pub fn maximum_level(&self) -> Level {
+        self.maximum_level
+    }
+
+    pub fn weight(&self) -> u32 {
+        self.weight
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct EncounterTable {
+    entries: Vec<EncounterEntry>,
+}
+
+impl EncounterTable {
+    pub fn new(entries: Vec<EncounterEntry>) -> Self {
+        EncounterTable { entries }
+    }
+
+    pub fn entries(&self) -> &[EncounterEntry] {
+        &self.entries
+    }
+
+    pub fn roll_entry<RNG: RandomNumberGenerator>(
+        &self,
+        random_number_generator: &RNG,
+    ) -> Result<&EncounterEntry, EncounterError> {
+        let total_weight = self
+            .entries
+            .iter()
+            .try_fold(0u32, |total, entry| total.checked_add(entry.weight))
+            .ok_or(EncounterError::TotalWeightTooLarge)?;
+        if total_weight == 0 {
+            return Err(EncounterError::EmptyTable);
+        }
+
+        let mut roll = random_number_generator.next_below(total_weight);
+        for entry in &self.entries {
+            if roll < entry.weight {
+                return Ok(entry);
+            }
+            roll -= entry.weight;
+        }
+        Err(EncounterError::EmptyTable)
+    }
+
+    pub fn roll<RNG: RandomNumberGenerator>(
+        &self,
+        random_number_generator: &RNG,
+    ) -> Result<Monster, EncounterError> {
+        let entry = self.roll_entry(random_number_generator)?;
+        let level_range = u32::from(entry.maximum_level.value() - entry.minimum_level.value()) + 1;
+        let level =
+            entry.minimum_level.value() + random_number_generator.next_below(level_range) as u8;
+
+        let mut individual_values = Stats::default();
+        for stat in Stat::ALL.iter() {
+            individual_values.set(*stat, random_number_generator.next_below(32) as u16);
+        }
+        let nature =
+            Nature::ALL[random_number_generator.next_below(Nature::ALL.len() as u32) as usize];
+
+        Ok(
+            MonsterBuilder::new(Rc::clone(&entry.species), Level::new(level))
+                .individual_values(
+                    IndividualValues::new(individual_values)
+                        .expect("random individual values are within range"),
+                )
+                .nature(nature)
+                .build()?,
+        )
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum EncounterError {
+    #[error("Encounter table has no entries")]
+    EmptyTable,
+    #[error("Minimum level {0} is above maximum level {1}")]
+    InvalidLevelRange(u8, u8),
+    #[error("Encounter entry for {0} has zero weight")]
+    ZeroWeight(String),
+    #[error("Encounter table weights add up to more than {}", u32::MAX)]
+    TotalWeightTooLarge,
+    #[error(transparent)]
+    MonsterBuilder(#[from] MonsterBuilderError),
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use spectral::prelude::*;
+
+    use crate::combat::MockRandomNumberGenerator;
+    use crate::monster::MonsterType;
+    use crate::Element;
+
+    use super::*;
+
+    fn species(name: &str) -> Rc<Species> {
+        Rc::new(Species::new(
+            name,
+            MonsterType::new(Element::Normal, None),
+            Stats::new(30, 56, 35, 25, 35, 72),
+            Vec::new(),
+            Vec::new(),
+        ))
+    }
+
+    fn under_test() -> EncounterTable {
+        EncounterTable::new(vec![
+            EncounterEntry::new(species("Rattata"), Level::new(2), Level::new(4), 70).unwrap(),
+            EncounterEntry::new(species("Pidgey"), Level::new(3), Level::new(5), 30).unwrap(),
+        ])
+    }
+
+    #[test]
+    fn rolls_entry_by_weight() {
+        let mut rng = MockRandomNumberGenerator::default();
+        rng.expect_next_below().with(eq(100)).returning(|_| 70);
+
+        assert_that(&under_test().roll_entry(&rng).unwrap().species().name()).is_equal_to("Pidgey");
+    }
+
+    #[test]
+    fn spawns_monster_within_level_range() {
+        let mut rng = MockRandomNumberGenerator::default();
+        rng.expect_next_below().with(eq(100)).returning(|_| 10);
+        rng.expect_next_below().with(eq(3)).returning(|_| 2);
+        rng.expect_next_below().with(eq(32)).returning(|_| 31);
+        rng.expect_next_below().with(eq(25)).returning(|_| 3);
+
+        let monster = under_test().roll(&rng).unwrap();
+
+        assert_that(&monster.species().unwrap().name()).is_equal_to("Rattata");
+        assert_that(&monster.level()).is_equal_to(Level::new(4));
+        assert_that(&monster.nature()).is_equal_to(Nature::Adamant);
+        assert_that(monster.individual_values()).is_equal_to(IndividualValues::perfect());
+    }
+
+    #[test]
+    fn fails_to_roll_empty_table() {
+        assert_that(
+            &EncounterTable::default()
+                .roll(&MockRandomNumberGenerator::default())
+                .err(),
+        )
+        .is_equal_to(Some(EncounterError::EmptyTable));
+    }
+
+    #[test]
+    fn fails_to_roll_table_with_overflowing_weights() {
+        let table = EncounterTable::new(vec![
+            EncounterEntry::new(species("Rattata"), Level::new(2), Level::new(4), u32::MAX)
+                .unwrap(),
+            EncounterEntry::new(species("Pidgey"), Level::new(3), Level::new(5), 1).unwrap(),
+        ]);
+
+        assert_that(
+            &table
+                .roll_entry(&MockRandomNumberGenerator::default())
+                .err(),
+        )
+        .is_equal_to(Some(EncounterError::TotalWeightTooLarge));
+    }
+
+    #[test]
+    fn rejects_inverted_level_range() {
+        assert_that(
+            &EncounterEntry::new(species("Rattata"), Level::new(5), Level::new(2), 1).err(),
+        )
+        .is_equal_to(Some(EncounterError::InvalidLevelRange(5, 2)));
+    }
+}
@@ -0,0 +1,59 @@
+use rust_decimal::Decimal;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Fraction {
+    numerator: u32,
+    denominator: u32,
+}
+
+impl Fraction {
+    pub fn new(numerator: u32, denominator: u32) -> Self {
+        Self::checked_new(numerator, denominator).expect("denominator must not be zero")
+    }
+
+    pub fn checked_new(numerator: u32, denominator: u32) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        Some(Fraction {
+            numerator,
+            denominator,
+        })
+    }
+
+    pub fn numerator(&self) -> u32 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> u32 {
+        self.denominator
+    }
+
+    pub fn of(&self, value: Decimal) -> Decimal {
+        value * Decimal::from(self.numerator) / Decimal::from(self.denominator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn takes_fraction_of_value() {
+        assert_that(&Fraction::new(1, 4).of(20.into())).is_equal_to(Decimal::from(5));
+    }
+
+    #[test]
+    fn rejects_zero_denominators() {
+        assert_that(&Fraction::checked_new(1, 0)).is_none();
+        assert_that(&Fraction::checked_new(1, 2)).is_equal_to(Some(Fraction::new(1, 2)));
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator must not be zero")]
+    fn new_panics_on_zero_denominators() {
+        Fraction::new(1, 0);
+    }
+}
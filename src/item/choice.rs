@@ -0,0 +1,182 @@
+use rust_decimal::Decimal;
+
+use crate::combat::{DamageMultiplier, PrimitiveDamageMultiplier};
+use crate::item::{DamageContext, HeldItem, HolderRole};
+use crate::monster::AttackCategory;
+use crate::moves::MoveDefinition;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ChoiceStat {
+    Attack,
+    SpecialAttack,
+    Speed,
+}
+
+pub struct ChoiceItem {
+    stat: ChoiceStat,
+    locked_move: Option<String>,
+}
+
+impl ChoiceItem {
+    pub fn new(stat: ChoiceStat) -> Self {
+        ChoiceItem {
+            stat,
+            locked_move: None,
+        }
+    }
+
+    pub fn band() -> Self {
+        Self::new(ChoiceStat::Attack)
+    }
+
+    pub fn specs() -> Self {
+        Self::new(ChoiceStat::SpecialAttack)
+    }
+
+    pub fn scarf() -> Self {
+        Self::new(ChoiceStat::Speed)
+    }
+
+    pub fn stat(&self) -> ChoiceStat {
+        self.stat
+    }
+
+    pub fn locked_move(&self) -> Option<&str> {
+        self.locked_move.as_deref()
+    }
+
+    fn boost() -> Decimal {
+        Decimal::new(15, 1)
+    }
+}
+
+impl HeldItem for ChoiceItem {
+    fn name(&self) -> &str {
+        match self.stat {
+            ChoiceStat::Attack => "Choice Band",
+            ChoiceStat::SpecialAttack => "Choice Specs",
+            ChoiceStat::Speed => "Choice Scarf",
+        }
+    }
+
+    fn damage_multiplier(&self, context: &DamageContext) -> DamageMultiplier {
+        let boosted = matches!(
+            (self.stat, context.attack().category()),
+            (ChoiceStat::Attack, AttackCategory::Physical)
+                | (ChoiceStat::SpecialAttack, AttackCategory::Special)
+        );
+
+        if boosted && context.role() == HolderRole::Attacker {
+            return DamageMultiplier::new(Self::boost());
+        }
+        PrimitiveDamageMultiplier::Single.into()
+    }
+
+    fn speed_multiplier(&self) -> Decimal {
+        if self.stat == ChoiceStat::Speed {
+            return Self::boost();
+        }
+        1.into()
+    }
+
+    fn permits_move(&self, move_definition: &MoveDefinition) -> bool {
+        self.locked_move
+            .as_deref()
+            .is_none_or(|locked_move| locked_move == move_definition.name())
+    }
+
+    fn on_move_used(&mut self, move_definition: &MoveDefinition) {
+        if self.locked_move.is_none() {
+            self.locked_move = Some(move_definition.name().to_string());
+        }
+    }
+
+    fn on_switch_out(&mut self) {
+        self.locked_move = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::monster::{Attack, AttackPower};
+    use crate::moves::MoveDamage;
+    use crate::Element;
+
+    use super::*;
+
+    fn attack(element: Element, category: AttackCategory) -> Attack {
+        Attack::new(element, category, AttackPower::new(10.into()))
+    }
+
+    fn move_definition(name: &str) -> MoveDefinition {
+        MoveDefinition::new(
+            name,
+            Element::Normal,
+            AttackCategory::Physical,
+            MoveDamage::Power(AttackPower::new(10.into())),
+            10,
+            Vec::new(),
+        )
+    }
+
+    #[test]
+    fn band_boosts_physical_attacks() {
+        let attack = attack(Element::Normal, AttackCategory::Physical);
+        let type_effectiveness = DamageMultiplier::new(1.into());
+
+        assert_that(&ChoiceItem::band().damage_multiplier(&DamageContext::new(
+            HolderRole::Attacker,
+            &attack,
+            &type_effectiveness,
+        )))
+        .is_equal_to(DamageMultiplier::new(Decimal::new(15, 1)));
+    }
+
+    #[test]
+    fn specs_do_not_boost_physical_attacks() {
+        let attack = attack(Element::Normal, AttackCategory::Physical);
+        let type_effectiveness = DamageMultiplier::new(1.into());
+
+        assert_that(&ChoiceItem::specs().damage_multiplier(&DamageContext::new(
+            HolderRole::Attacker,
+            &attack,
+            &type_effectiveness,
+        )))
+        .is_equal_to(DamageMultiplier::new(1.into()));
+    }
+
+    #[test]
+    fn scarf_boosts_speed() {
+        assert_that(&ChoiceItem::scarf().speed_multiplier()).is_equal_to(Decimal::new(15, 1));
+    }
+
+    #[test]
+    fn locks_holder_into_first_move_used() {
+        let mut item = ChoiceItem::band();
+        item.on_move_used(&move_definition("Tackle"));
+
+        assert_that(&item.locked_move()).is_equal_to(Some("Tackle"));
+        assert_that(&item.permits_move(&move_definition("Tackle"))).is_true();
+        assert_that(&item.permits_move(&move_definition("Pound"))).is_false();
+    }
+
+    #[test]
+    fn keeps_the_first_lock_when_other_moves_are_used() {
+        let mut item = ChoiceItem::band();
+        item.on_move_used(&move_definition("Tackle"));
+        item.on_move_used(&move_definition("Pound"));
+
+        assert_that(&item.locked_move()).is_equal_to(Some("Tackle"));
+    }
+
+    #[test]
+    fn releases_lock_on_switch_out() {
+        let mut item = ChoiceItem::band();
+        item.on_move_used(&move_definition("Tackle"));
+        item.on_switch_out();
+
+        assert_that(&item.locked_move().is_none()).is_true();
+    }
+}
@@ -0,0 +1,79 @@
+use crate::combat::{ArithmeticMode, DamageMultiplier};
+use crate::monster::{Attack, Damage, Monster};
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum HolderRole {
+    Attacker,
+    Defender,
+}
+
+pub struct DamageContext<'a> {
+    role: HolderRole,
+    attack: &'a Attack,
+    type_effectiveness: &'a DamageMultiplier,
+}
+
+impl<'a> DamageContext<'a> {
+    pub fn new(
+        role: HolderRole,
+        attack: &'a Attack,
+        type_effectiveness: &'a DamageMultiplier,
+    ) -> Self {
+        DamageContext {
+            role,
+            attack,
+            type_effectiveness,
+        }
+    }
+
+    pub fn role(&self) -> HolderRole {
+        self.role
+    }
+
+    pub fn attack(&self) -> &Attack {
+        self.attack
+    }
+
+    pub fn type_effectiveness(&self) -> &DamageMultiplier {
+        self.type_effectiveness
+    }
+}
+
+pub struct HitContext<'a> {
+    damage_context: DamageContext<'a>,
+    holder: &'a Monster,
+    damage: &'a Damage,
+    arithmetic_mode: ArithmeticMode,
+}
+
+impl<'a> HitContext<'a> {
+    pub fn new(damage_context: DamageContext<'a>, holder: &'a Monster, damage: &'a Damage) -> Self {
+        HitContext {
+            damage_context,
+            holder,
+            damage,
+            arithmetic_mode: ArithmeticMode::default(),
+        }
+    }
+
+    pub fn with_arithmetic_mode(mut self, arithmetic_mode: ArithmeticMode) -> Self {
+        self.arithmetic_mode = arithmetic_mode;
+        self
+    }
+
+    pub fn damage_context(&self) -> &DamageContext<'a> {
+        &self.damage_context
+    }
+
+    pub fn holder(&self) -> &Monster {
+        self.holder
+    }
+
+    pub fn damage(&self) -> &Damage {
+        self.damage
+    }
+
+    pub fn arithmetic_mode(&self) -> ArithmeticMode {
+        self.arithmetic_mode
+    }
+}
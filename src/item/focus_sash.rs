@@ -0,0 +1,56 @@
+use crate::item::HeldItem;
+use crate::monster::{Damage, Monster};
+
+#[derive(Default)]
+pub struct FocusSash;
+
+impl FocusSash {
+    pub fn new() -> Self {
+        FocusSash
+    }
+}
+
+impl HeldItem for FocusSash {
+    fn name(&self) -> &str {
+        "Focus Sash"
+    }
+
+    fn endures_hit(&self, holder: &Monster, damage: &Damage) -> bool {
+        holder.health() == holder.max_health() && damage.value() >= holder.health().value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::monster::{Health, MonsterType};
+    use crate::Element;
+
+    use super::*;
+
+    fn holder() -> Monster {
+        Monster::new(
+            MonsterType::new(Element::Normal, None),
+            Health::new(10.into()),
+        )
+    }
+
+    #[test]
+    fn endures_knockout_hit_at_full_health() {
+        assert_that(&FocusSash::new().endures_hit(&holder(), &Damage::new(20.into()))).is_true();
+    }
+
+    #[test]
+    fn does_not_endure_hit_when_not_at_full_health() {
+        let mut holder = holder();
+        holder.receive_damage(Damage::new(1.into()));
+
+        assert_that(&FocusSash::new().endures_hit(&holder, &Damage::new(20.into()))).is_false();
+    }
+
+    #[test]
+    fn does_not_activate_on_non_lethal_hit() {
+        assert_that(&FocusSash::new().endures_hit(&holder(), &Damage::new(5.into()))).is_false();
+    }
+}
@@ -0,0 +1,84 @@
+use crate::combat::ArithmeticMode;
+use crate::item::{fraction_of_max_health, HeldItem, ItemEffect};
+use crate::monster::{Health, Monster};
+
+#[derive(Default)]
+pub struct Leftovers;
+
+impl Leftovers {
+    pub fn new() -> Self {
+        Leftovers
+    }
+}
+
+impl HeldItem for Leftovers {
+    fn name(&self) -> &str {
+        "Leftovers"
+    }
+
+    fn on_end_of_turn(
+        &mut self,
+        holder: &Monster,
+        arithmetic_mode: ArithmeticMode,
+    ) -> Vec<ItemEffect> {
+        if holder.health().value() >= holder.max_health().value() {
+            return Vec::new();
+        }
+        vec![ItemEffect::Heal(Health::new(fraction_of_max_health(
+            holder,
+            16,
+            arithmetic_mode,
+        )))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use spectral::prelude::*;
+
+    use crate::monster::{Damage, MonsterType};
+    use crate::Element;
+
+    use super::*;
+
+    fn holder() -> Monster {
+        Monster::new(
+            MonsterType::new(Element::Normal, None),
+            Health::new(32.into()),
+        )
+    }
+
+    #[test]
+    fn heals_sixteenth_of_max_health_at_end_of_turn() {
+        let mut holder = holder();
+        holder.receive_damage(Damage::new(10.into()));
+
+        assert_that(&Leftovers::new().on_end_of_turn(&holder, ArithmeticMode::Decimal))
+            .is_equal_to(vec![ItemEffect::Heal(Health::new(2.into()))]);
+    }
+
+    #[test]
+    fn does_nothing_at_full_health() {
+        assert_that(
+            &Leftovers::new()
+                .on_end_of_turn(&holder(), ArithmeticMode::Decimal)
+                .is_empty(),
+        )
+        .is_true();
+    }
+
+    #[test]
+    fn fixed_point_mode_floors_healing_to_at_least_one() {
+        let mut holder = Monster::new(
+            MonsterType::new(Element::Normal, None),
+            Health::new(20.into()),
+        );
+        holder.receive_damage(Damage::new(10.into()));
+
+        assert_that(&Leftovers::new().on_end_of_turn(&holder, ArithmeticMode::Decimal))
+            .is_equal_to(vec![ItemEffect::Heal(Health::new(Decimal::new(125, 2)))]);
+        assert_that(&Leftovers::new().on_end_of_turn(&holder, ArithmeticMode::FixedPoint))
+            .is_equal_to(vec![ItemEffect::Heal(Health::new(1.into()))]);
+    }
+}
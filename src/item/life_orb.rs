@@ -0,0 +1,114 @@
+use rust_decimal::Decimal;
+
+use crate::combat::{DamageMultiplier, PrimitiveDamageMultiplier};
+use crate::item::{
+    fraction_of_max_health, DamageContext, HeldItem, HitContext, HolderRole, ItemEffect,
+};
+use crate::monster::Damage;
+
+#[derive(Default)]
+pub struct LifeOrb;
+
+impl LifeOrb {
+    pub fn new() -> Self {
+        LifeOrb
+    }
+}
+
+impl HeldItem for LifeOrb {
+    fn name(&self) -> &str {
+        "Life Orb"
+    }
+
+    fn damage_multiplier(&self, context: &DamageContext) -> DamageMultiplier {
+        if context.role() == HolderRole::Attacker {
+            return DamageMultiplier::new(Decimal::new(13, 1));
+        }
+        PrimitiveDamageMultiplier::Single.into()
+    }
+
+    fn on_hit(&mut self, context: &HitContext) -> Vec<ItemEffect> {
+        if context.damage_context().role() != HolderRole::Attacker
+            || context.damage().value() <= 0.into()
+        {
+            return Vec::new();
+        }
+        vec![ItemEffect::Recoil(Damage::new(fraction_of_max_health(
+            context.holder(),
+            10,
+            context.arithmetic_mode(),
+        )))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::monster::{Attack, AttackCategory, AttackPower, Health, Monster, MonsterType};
+    use crate::Element;
+
+    use super::*;
+
+    fn attack() -> Attack {
+        Attack::new(
+            Element::Normal,
+            AttackCategory::Special,
+            AttackPower::new(10.into()),
+        )
+    }
+
+    fn holder() -> Monster {
+        Monster::new(
+            MonsterType::new(Element::Normal, None),
+            Health::new(50.into()),
+        )
+    }
+
+    #[test]
+    fn boosts_holders_attacks() {
+        let attack = attack();
+        let type_effectiveness = DamageMultiplier::new(1.into());
+
+        assert_that(&LifeOrb::new().damage_multiplier(&DamageContext::new(
+            HolderRole::Attacker,
+            &attack,
+            &type_effectiveness,
+        )))
+        .is_equal_to(DamageMultiplier::new(Decimal::new(13, 1)));
+    }
+
+    #[test]
+    fn inflicts_tenth_of_max_health_recoil_after_damaging_attack() {
+        let attack = attack();
+        let holder = holder();
+        let type_effectiveness = DamageMultiplier::new(1.into());
+        let damage = Damage::new(10.into());
+
+        assert_that(&LifeOrb::new().on_hit(&HitContext::new(
+            DamageContext::new(HolderRole::Attacker, &attack, &type_effectiveness),
+            &holder,
+            &damage,
+        )))
+        .is_equal_to(vec![ItemEffect::Recoil(Damage::new(5.into()))]);
+    }
+
+    #[test]
+    fn does_not_inflict_recoil_when_holder_is_hit() {
+        let attack = attack();
+        let holder = holder();
+        let type_effectiveness = DamageMultiplier::new(1.into());
+        let damage = Damage::new(10.into());
+
+        assert_that(
+            &LifeOrb::new()
+                .on_hit(&HitContext::new(
+                    DamageContext::new(HolderRole::Defender, &attack, &type_effectiveness),
+                    &holder,
+                    &damage,
+                ))
+                .is_empty(),
+        )
+        .is_true();
+    }
+}
@@ -0,0 +1,73 @@
+use crate::combat::ArithmeticMode;
+use crate::item::{HeldItem, ItemEffect};
+use crate::monster::Monster;
+
+#[derive(Default)]
+pub struct LumBerry;
+
+impl LumBerry {
+    pub fn new() -> Self {
+        LumBerry
+    }
+
+    fn cure(holder: &Monster) -> Vec<ItemEffect> {
+        if holder.status().is_none() {
+            return Vec::new();
+        }
+        vec![ItemEffect::CureStatus, ItemEffect::Consumed]
+    }
+}
+
+impl HeldItem for LumBerry {
+    fn name(&self) -> &str {
+        "Lum Berry"
+    }
+
+    fn on_status_inflicted(&mut self, holder: &Monster) -> Vec<ItemEffect> {
+        Self::cure(holder)
+    }
+
+    fn on_end_of_turn(
+        &mut self,
+        holder: &Monster,
+        _arithmetic_mode: ArithmeticMode,
+    ) -> Vec<ItemEffect> {
+        Self::cure(holder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::monster::{Health, MonsterType, StatusCondition};
+    use crate::Element;
+
+    use super::*;
+
+    fn holder() -> Monster {
+        Monster::new(
+            MonsterType::new(Element::Normal, None),
+            Health::new(10.into()),
+        )
+    }
+
+    #[test]
+    fn cures_status_and_is_consumed() {
+        let mut holder = holder();
+        holder.inflict_status(StatusCondition::Burn);
+
+        assert_that(&LumBerry::new().on_status_inflicted(&holder))
+            .is_equal_to(vec![ItemEffect::CureStatus, ItemEffect::Consumed]);
+    }
+
+    #[test]
+    fn does_nothing_without_status() {
+        assert_that(
+            &LumBerry::new()
+                .on_end_of_turn(&holder(), ArithmeticMode::Decimal)
+                .is_empty(),
+        )
+        .is_true();
+    }
+}
@@ -0,0 +1,165 @@
+use rust_decimal::Decimal;
+
+pub use choice::{ChoiceItem, ChoiceStat};
+pub use context::{DamageContext, HitContext, HolderRole};
+pub use focus_sash::FocusSash;
+pub use leftovers::Leftovers;
+pub use life_orb::LifeOrb;
+pub use lum_berry::LumBerry;
+pub use resist_berry::ResistBerry;
+pub use sitrus_berry::SitrusBerry;
+pub use type_boosting_item::TypeBoostingItem;
+
+use crate::combat::{ArithmeticMode, DamageMultiplier, PrimitiveDamageMultiplier};
+use crate::monster::{Damage, Health, Monster};
+use crate::moves::MoveDefinition;
+use crate::{Element, Fraction};
+
+mod choice;
+mod context;
+mod focus_sash;
+mod leftovers;
+mod life_orb;
+mod lum_berry;
+mod resist_berry;
+mod sitrus_berry;
+mod type_boosting_item;
+
+pub trait HeldItem {
+    fn name(&self) -> &str;
+
+    fn damage_multiplier(&self, _context: &DamageContext) -> DamageMultiplier {
+        PrimitiveDamageMultiplier::Single.into()
+    }
+
+    fn speed_multiplier(&self) -> Decimal {
+        1.into()
+    }
+
+    fn permits_move(&self, _move_definition: &MoveDefinition) -> bool {
+        true
+    }
+
+    fn on_move_used(&mut self, _move_definition: &MoveDefinition) {}
+
+    fn endures_hit(&self, _holder: &Monster, _damage: &Damage) -> bool {
+        false
+    }
+
+    fn on_hit(&mut self, _context: &HitContext) -> Vec<ItemEffect> {
+        Vec::new()
+    }
+
+    fn on_status_inflicted(&mut self, _holder: &Monster) -> Vec<ItemEffect> {
+        Vec::new()
+    }
+
+    fn on_end_of_turn(
+        &mut self,
+        _holder: &Monster,
+        _arithmetic_mode: ArithmeticMode,
+    ) -> Vec<ItemEffect> {
+        Vec::new()
+    }
+
+    fn on_switch_out(&mut self) {}
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, PartialOrd, Ord, Default)]
+pub struct ItemId(u16);
+
+impl ItemId {
+    pub const fn new(value: u16) -> Self {
+        ItemId(value)
+    }
+
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+fn built_in_items() -> Vec<(u16, Box<dyn HeldItem>)> {
+    vec![
+        (1, Box::new(ChoiceItem::band())),
+        (2, Box::new(ChoiceItem::specs())),
+        (3, Box::new(ChoiceItem::scarf())),
+        (4, Box::new(FocusSash::new())),
+        (5, Box::new(Leftovers::new())),
+        (6, Box::new(LifeOrb::new())),
+        (7, Box::new(LumBerry::new())),
+        (8, Box::new(SitrusBerry::new())),
+        (32, Box::new(ResistBerry::new(Element::Bug))),
+        (33, Box::new(ResistBerry::new(Element::Dark))),
+        (34, Box::new(ResistBerry::new(Element::Dragon))),
+        (35, Box::new(ResistBerry::new(Element::Electric))),
+        (36, Box::new(ResistBerry::new(Element::Fairy))),
+        (37, Box::new(ResistBerry::new(Element::Fighting))),
+        (38, Box::new(ResistBerry::new(Element::Fire))),
+        (39, Box::new(ResistBerry::new(Element::Flying))),
+        (40, Box::new(ResistBerry::new(Element::Ghost))),
+        (41, Box::new(ResistBerry::new(Element::Grass))),
+        (42, Box::new(ResistBerry::new(Element::Ground))),
+        (43, Box::new(ResistBerry::new(Element::Ice))),
+        (44, Box::new(ResistBerry::new(Element::Normal))),
+        (45, Box::new(ResistBerry::new(Element::Poison))),
+        (46, Box::new(ResistBerry::new(Element::Psychic))),
+        (47, Box::new(ResistBerry::new(Element::Rock))),
+        (48, Box::new(ResistBerry::new(Element::Steel))),
+        (49, Box::new(ResistBerry::new(Element::Water))),
+        (64, Box::new(TypeBoostingItem::new(Element::Bug))),
+        (65, Box::new(TypeBoostingItem::new(Element::Dark))),
+        (66, Box::new(TypeBoostingItem::new(Element::Dragon))),
+        (67, Box::new(TypeBoostingItem::new(Element::Electric))),
+        (68, Box::new(TypeBoostingItem::new(Element::Fairy))),
+        (69, Box::new(TypeBoostingItem::new(Element::Fighting))),
+        (70, Box::new(TypeBoostingItem::new(Element::Fire))),
+        (71, Box::new(TypeBoostingItem::new(Element::Flying))),
+        (72, Box::new(TypeBoostingItem::new(Element::Ghost))),
+        (73, Box::new(TypeBoostingItem::new(Element::Grass))),
+        (74, Box::new(TypeBoostingItem::new(Element::Ground))),
+        (75, Box::new(TypeBoostingItem::new(Element::Ice))),
+        (76, Box::new(TypeBoostingItem::new(Element::Normal))),
+        (77, Box::new(TypeBoostingItem::new(Element::Poison))),
+        (78, Box::new(TypeBoostingItem::new(Element::Psychic))),
+        (79, Box::new(TypeBoostingItem::new(Element::Rock))),
+        (80, Box::new(TypeBoostingItem::new(Element::Steel))),
+        (81, Box::new(TypeBoostingItem::new(Element::Water))),
+    ]
+}
+
+pub fn held_item_named(name: &str) -> Option<Box<dyn HeldItem>> {
+    built_in_items()
+        .into_iter()
+        .map(|(_, held_item)| held_item)
+        .find(|candidate| candidate.name().eq_ignore_ascii_case(name.trim()))
+}
+
+pub fn held_item_with_id(id: ItemId) -> Option<Box<dyn HeldItem>> {
+    built_in_items()
+        .into_iter()
+        .find(|(candidate, _)| *candidate == id.value())
+        .map(|(_, held_item)| held_item)
+}
+
+pub fn held_item_id(held_item: &dyn HeldItem) -> Option<ItemId> {
+    built_in_items()
+        .into_iter()
+        .find(|(_, candidate)| candidate.name() == held_item.name())
+        .map(|(id, _)| ItemId::new(id))
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ItemEffect {
+    Heal(Health),
+    Recoil(Damage),
+    CureStatus,
+    Consumed,
+}
+
+fn fraction_of_max_health(
+    holder: &Monster,
+    denominator: u32,
+    arithmetic_mode: ArithmeticMode,
+) -> Decimal {
+    arithmetic_mode.fraction_of(&Fraction::new(1, denominator), holder.max_health().value())
+}
@@ -0,0 +1,136 @@
+use crate::combat::{DamageMultiplier, PrimitiveDamageMultiplier};
+use crate::item::{DamageContext, HeldItem, HitContext, HolderRole, ItemEffect};
+use crate::Element;
+
+pub struct ResistBerry {
+    element: Element,
+}
+
+impl ResistBerry {
+    pub fn new(element: Element) -> Self {
+        ResistBerry { element }
+    }
+
+    pub fn element(&self) -> &Element {
+        &self.element
+    }
+
+    fn activates(&self, context: &DamageContext) -> bool {
+        if context.role() != HolderRole::Defender || context.attack().element() != &self.element {
+            return false;
+        }
+        self.element == Element::Normal || context.type_effectiveness().value() > 1.into()
+    }
+}
+
+impl HeldItem for ResistBerry {
+    fn name(&self) -> &str {
+        match self.element {
+            Element::Bug => "Tanga Berry",
+            Element::Dark => "Colbur Berry",
+            Element::Dragon => "Haban Berry",
+            Element::Electric => "Wacan Berry",
+            Element::Fairy => "Roseli Berry",
+            Element::Fighting => "Chople Berry",
+            Element::Fire => "Occa Berry",
+            Element::Flying => "Coba Berry",
+            Element::Ghost => "Kasib Berry",
+            Element::Grass => "Rindo Berry",
+            Element::Ground => "Shuca Berry",
+            Element::Ice => "Yache Berry",
+            Element::Normal => "Chilan Berry",
+            Element::Poison => "Kebia Berry",
+            Element::Psychic => "Payapa Berry",
+            Element::Rock => "Charti Berry",
+            Element::Steel => "Babiri Berry",
+            Element::Water => "Passho Berry",
+            Element::Custom(_) => "Resist Berry",
+        }
+    }
+
+    fn damage_multiplier(&self, context: &DamageContext) -> DamageMultiplier {
+        if self.activates(context) {
+            return PrimitiveDamageMultiplier::Half.into();
+        }
+        PrimitiveDamageMultiplier::Single.into()
+    }
+
+    fn on_hit(&mut self, context: &HitContext) -> Vec<ItemEffect> {
+        if self.activates(context.damage_context()) {
+            return vec![ItemEffect::Consumed];
+        }
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use spectral::prelude::*;
+
+    use crate::monster::{Attack, AttackCategory, AttackPower};
+
+    use super::*;
+
+    fn attack(element: Element) -> Attack {
+        Attack::new(
+            element,
+            AttackCategory::Physical,
+            AttackPower::new(10.into()),
+        )
+    }
+
+    fn multiplier(
+        berry: &ResistBerry,
+        attack_element: Element,
+        effectiveness: Decimal,
+    ) -> DamageMultiplier {
+        let attack = attack(attack_element);
+        let type_effectiveness = DamageMultiplier::new(effectiveness);
+        berry.damage_multiplier(&DamageContext::new(
+            HolderRole::Defender,
+            &attack,
+            &type_effectiveness,
+        ))
+    }
+
+    #[test]
+    fn halves_super_effective_damage_of_its_element() {
+        assert_that(&multiplier(
+            &ResistBerry::new(Element::Fire),
+            Element::Fire,
+            2.into(),
+        ))
+        .is_equal_to(DamageMultiplier::new(Decimal::new(5, 1)));
+    }
+
+    #[test]
+    fn ignores_neutral_damage_of_its_element() {
+        assert_that(&multiplier(
+            &ResistBerry::new(Element::Fire),
+            Element::Fire,
+            1.into(),
+        ))
+        .is_equal_to(DamageMultiplier::new(1.into()));
+    }
+
+    #[test]
+    fn ignores_other_elements() {
+        assert_that(&multiplier(
+            &ResistBerry::new(Element::Fire),
+            Element::Water,
+            2.into(),
+        ))
+        .is_equal_to(DamageMultiplier::new(1.into()));
+    }
+
+    #[test]
+    fn normal_berry_halves_neutral_normal_damage() {
+        assert_that(&multiplier(
+            &ResistBerry::new(Element::Normal),
+            Element::Normal,
+            1.into(),
+        ))
+        .is_equal_to(DamageMultiplier::new(Decimal::new(5, 1)));
+    }
+}
@@ -0,0 +1,100 @@
+use crate::combat::ArithmeticMode;
+use crate::item::{fraction_of_max_health, HeldItem, HitContext, HolderRole, ItemEffect};
+use crate::monster::{Health, Monster};
+
+#[derive(Default)]
+pub struct SitrusBerry;
+
+impl SitrusBerry {
+    pub fn new() -> Self {
+        SitrusBerry
+    }
+
+    fn restore(holder: &Monster, arithmetic_mode: ArithmeticMode) -> Vec<ItemEffect> {
+        let health = holder.health().value();
+        if health <= 0.into() || health > fraction_of_max_health(holder, 2, arithmetic_mode) {
+            return Vec::new();
+        }
+        vec![
+            ItemEffect::Heal(Health::new(fraction_of_max_health(
+                holder,
+                4,
+                arithmetic_mode,
+            ))),
+            ItemEffect::Consumed,
+        ]
+    }
+}
+
+impl HeldItem for SitrusBerry {
+    fn name(&self) -> &str {
+        "Sitrus Berry"
+    }
+
+    fn on_hit(&mut self, context: &HitContext) -> Vec<ItemEffect> {
+        if context.damage_context().role() != HolderRole::Defender {
+            return Vec::new();
+        }
+        Self::restore(context.holder(), context.arithmetic_mode())
+    }
+
+    fn on_end_of_turn(
+        &mut self,
+        holder: &Monster,
+        arithmetic_mode: ArithmeticMode,
+    ) -> Vec<ItemEffect> {
+        Self::restore(holder, arithmetic_mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use spectral::prelude::*;
+
+    use crate::monster::{Damage, MonsterType};
+    use crate::Element;
+
+    use super::*;
+
+    fn holder_with_damage(damage: Decimal) -> Monster {
+        let mut holder = Monster::new(
+            MonsterType::new(Element::Normal, None),
+            Health::new(40.into()),
+        );
+        holder.receive_damage(Damage::new(damage));
+        holder
+    }
+
+    #[test]
+    fn restores_quarter_of_max_health_at_half_health() {
+        assert_that(
+            &SitrusBerry::new()
+                .on_end_of_turn(&holder_with_damage(20.into()), ArithmeticMode::Decimal),
+        )
+        .is_equal_to(vec![
+            ItemEffect::Heal(Health::new(10.into())),
+            ItemEffect::Consumed,
+        ]);
+    }
+
+    #[test]
+    fn does_nothing_above_half_health() {
+        assert_that(
+            &SitrusBerry::new()
+                .on_end_of_turn(&holder_with_damage(10.into()), ArithmeticMode::Decimal)
+                .is_empty(),
+        )
+        .is_true();
+    }
+
+    #[test]
+    fn does_nothing_when_holder_is_defeated() {
+        assert_that(
+            &SitrusBerry::new()
+                .on_end_of_turn(&holder_with_damage(40.into()), ArithmeticMode::Decimal)
+                .is_empty(),
+        )
+        .is_true();
+    }
+}
@@ -0,0 +1,93 @@
+use rust_decimal::Decimal;
+
+use crate::combat::{DamageMultiplier, PrimitiveDamageMultiplier};
+use crate::item::{DamageContext, HeldItem, HolderRole};
+use crate::Element;
+
+pub struct TypeBoostingItem {
+    element: Element,
+}
+
+impl TypeBoostingItem {
+    pub fn new(element: Element) -> Self {
+        TypeBoostingItem { element }
+    }
+
+    pub fn element(&self) -> &Element {
+        &self.element
+    }
+}
+
+impl HeldItem for TypeBoostingItem {
+    fn name(&self) -> &str {
+        match self.element {
+            Element::Bug => "Silver Powder",
+            Element::Dark => "Black Glasses",
+            Element::Dragon => "Dragon Fang",
+            Element::Electric => "Magnet",
+            Element::Fairy => "Fairy Feather",
+            Element::Fighting => "Black Belt",
+            Element::Fire => "Charcoal",
+            Element::Flying => "Sharp Beak",
+            Element::Ghost => "Spell Tag",
+            Element::Grass => "Miracle Seed",
+            Element::Ground => "Soft Sand",
+            Element::Ice => "Never-Melt Ice",
+            Element::Normal => "Silk Scarf",
+            Element::Poison => "Poison Barb",
+            Element::Psychic => "Twisted Spoon",
+            Element::Rock => "Hard Stone",
+            Element::Steel => "Metal Coat",
+            Element::Water => "Mystic Water",
+            Element::Custom(_) => "Type Boosting Item",
+        }
+    }
+
+    fn damage_multiplier(&self, context: &DamageContext) -> DamageMultiplier {
+        if context.role() == HolderRole::Attacker && context.attack().element() == &self.element {
+            return DamageMultiplier::new(Decimal::new(12, 1));
+        }
+        PrimitiveDamageMultiplier::Single.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::monster::{Attack, AttackCategory, AttackPower};
+
+    use super::*;
+
+    fn multiplier(role: HolderRole, attack_element: Element) -> DamageMultiplier {
+        let attack = Attack::new(
+            attack_element,
+            AttackCategory::Special,
+            AttackPower::new(10.into()),
+        );
+        let type_effectiveness = DamageMultiplier::new(1.into());
+        TypeBoostingItem::new(Element::Fire).damage_multiplier(&DamageContext::new(
+            role,
+            &attack,
+            &type_effectiveness,
+        ))
+    }
+
+    #[test]
+    fn boosts_attacks_of_its_element() {
+        assert_that(&multiplier(HolderRole::Attacker, Element::Fire))
+            .is_equal_to(DamageMultiplier::new(Decimal::new(12, 1)));
+    }
+
+    #[test]
+    fn does_not_boost_attacks_of_other_elements() {
+        assert_that(&multiplier(HolderRole::Attacker, Element::Water))
+            .is_equal_to(DamageMultiplier::new(1.into()));
+    }
+
+    #[test]
+    fn does_not_boost_attacks_against_holder() {
+        assert_that(&multiplier(HolderRole::Defender, Element::Fire))
+            .is_equal_to(DamageMultiplier::new(1.into()));
+    }
+}
@@ -1,5 +1,19 @@
-pub use elements::Element;
+pub use element_registry::{
+    ElementDefinition, ElementDisplay, ElementRegistry, ElementRegistryError,
+};
+pub use elements::{Element, ElementId, ParseElementError};
+pub use fraction::Fraction;
 
 pub mod combat;
+pub mod dex;
+mod element_registry;
 mod elements;
+pub mod encounter;
+mod fraction;
+pub mod item;
 pub mod monster;
+pub mod moves;
+pub mod progression;
+pub mod ruleset;
+pub mod species;
+pub mod team;
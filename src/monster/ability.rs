@@ -0,0 +1,40 @@
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, PartialOrd, Ord, Default)]
+pub struct AbilityId(u16);
+
+impl AbilityId {
+    pub const fn new(value: u16) -> Self {
+        AbilityId(value)
+    }
+
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Ability {
+    id: AbilityId,
+    name: String,
+}
+
+impl Ability {
+    pub fn new(name: &str) -> Self {
+        Ability {
+            id: AbilityId::default(),
+            name: name.to_string(),
+        }
+    }
+
+    pub fn with_id(mut self, id: AbilityId) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn id(&self) -> AbilityId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
@@ -0,0 +1,6 @@
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum AttackCategory {
+    Physical,
+    Special,
+    Status,
+}
@@ -1,23 +1,35 @@
+pub use category::AttackCategory;
 pub use power::AttackPower;
 
 use crate::Element;
 
+mod category;
 mod power;
 
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Attack {
     element: Element,
+    category: AttackCategory,
     power: AttackPower,
 }
 
 impl Attack {
-    pub fn new(element: Element, power: AttackPower) -> Self {
-        Attack { element, power }
+    pub fn new(element: Element, category: AttackCategory, power: AttackPower) -> Self {
+        Attack {
+            element,
+            category,
+            power,
+        }
     }
 
     pub fn element(&self) -> &Element {
         &self.element
     }
 
+    pub fn category(&self) -> &AttackCategory {
+        &self.category
+    }
+
     pub fn power(&self) -> &AttackPower {
         &self.power
     }
@@ -30,7 +42,11 @@ mod tests {
     use super::*;
 
     fn under_test() -> Attack {
-        Attack::new(Element::Normal, AttackPower::new(10.into()))
+        Attack::new(
+            Element::Normal,
+            AttackCategory::Physical,
+            AttackPower::new(10.into()),
+        )
     }
 
     #[test]
@@ -38,6 +54,11 @@ mod tests {
         assert_that(&under_test().element()).is_equal_to(&Element::Normal);
     }
 
+    #[test]
+    fn returns_its_category() {
+        assert_that(&under_test().category()).is_equal_to(&AttackCategory::Physical);
+    }
+
     #[test]
     fn returns_its_power() {
         assert_that(&under_test().power()).is_equal_to(&AttackPower::new(10.into()));
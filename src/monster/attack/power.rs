@@ -1,6 +1,6 @@
 use rust_decimal::Decimal;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub struct AttackPower(Decimal);
 
 impl AttackPower {
@@ -22,6 +22,6 @@ mod tests {
     #[test]
     fn returns_integer_value() {
         let attack_value = 10.into();
-        assert_that(&AttackPower::new(attack_value).value()).is_equal_to(&attack_value);
+        assert_that(&AttackPower::new(attack_value).value()).is_equal_to(attack_value);
     }
 }
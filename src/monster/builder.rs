@@ -0,0 +1,325 @@
+use std::rc::Rc;
+
+use rust_decimal::Decimal;
+
+use crate::item::HeldItem;
+use crate::monster::{
+    calculate_stats, Ability, EffortValues, Health, IndividualValues, Level, Monster, MovesetError,
+    Nature, Stat,
+};
+use crate::moves::MoveDefinition;
+use crate::species::Species;
+
+pub struct MonsterBuilder {
+    species: Rc<Species>,
+    level: Level,
+    individual_values: IndividualValues,
+    effort_values: EffortValues,
+    nature: Nature,
+    ability: Option<Ability>,
+    moves: Vec<MoveDefinition>,
+    held_item: Option<Box<dyn HeldItem>>,
+    friendship: Option<u8>,
+    fill_defaults: bool,
+}
+
+impl MonsterBuilder {
+    pub fn new(species: Rc<Species>, level: Level) -> Self {
+        MonsterBuilder {
+            species,
+            level,
+            individual_values: IndividualValues::default(),
+            effort_values: EffortValues::default(),
+            nature: Nature::default(),
+            ability: None,
+            moves: Vec::new(),
+            held_item: None,
+            friendship: None,
+            fill_defaults: true,
+        }
+    }
+
+    pub fn individual_values(mut self, individual_values: IndividualValues) -> Self {
+        self.individual_values = individual_values;
+        self
+    }
+
+    pub fn effort_values(mut self, effort_values: EffortValues) -> Self {
+        self.effort_values = effort_values;
+        self
+    }
+
+    pub fn nature(mut self, nature: Nature) -> Self {
+        self.nature = nature;
+        self
+    }
+
+    pub fn ability(mut self, ability: Ability) -> Self {
+        self.ability = Some(ability);
+        self
+    }
+
+    pub fn moves(mut self, moves: Vec<MoveDefinition>) -> Self {
+        self.moves = moves;
+        self
+    }
+
+    pub fn held_item(mut self, held_item: Box<dyn HeldItem>) -> Self {
+        self.held_item = Some(held_item);
+        self
+    }
+
+    pub fn friendship(mut self, friendship: u8) -> Self {
+        self.friendship = Some(friendship);
+        self
+    }
+
+    pub fn without_defaults(mut self) -> Self {
+        self.fill_defaults = false;
+        self
+    }
+
+    fn latest_level_up_moves(species: &Species, level: Level) -> Vec<MoveDefinition> {
+        let mut moves: Vec<MoveDefinition> = Vec::new();
+        for (_, move_definition) in species
+            .level_up_moves()
+            .iter()
+            .filter(|(learned_at, _)| *learned_at <= level)
+            .rev()
+        {
+            if moves.len() == 4 {
+                break;
+            }
+            if moves
+                .iter()
+                .all(|known| known.name() != move_definition.name())
+            {
+                moves.insert(0, move_definition.clone());
+            }
+        }
+        moves
+    }
+
+    pub fn build(self) -> Result<Monster, MonsterBuilderError> {
+        let ability = match self.ability {
+            Some(ability) => Some(
+                self.species
+                    .ability_named(ability.name())
+                    .cloned()
+                    .ok_or_else(|| {
+                        MonsterBuilderError::AbilityNotAvailable(ability.name().to_string())
+                    })?,
+            ),
+            None if self.fill_defaults => self.species.abilities().first().cloned(),
+            None => None,
+        };
+
+        let stats = calculate_stats(
+            self.species.base_stats(),
+            &self.individual_values,
+            &self.effort_values,
+            self.level,
+            self.nature,
+        );
+        let mut monster = Monster::new(
+            self.species.monster_type().clone(),
+            Health::new(Decimal::from(stats.get(Stat::Hp))),
+        );
+        let moves = if self.moves.is_empty() && self.fill_defaults {
+            Self::latest_level_up_moves(&self.species, self.level)
+        } else {
+            self.moves
+        };
+        for move_definition in moves {
+            if !self.species.can_learn(&move_definition) {
+                return Err(MonsterBuilderError::MoveNotLearnable(
+                    move_definition.name().to_string(),
+                ));
+            }
+            monster.moveset_mut().learn(move_definition)?;
+        }
+        if let Some(held_item) = self.held_item {
+            monster.give_held_item(held_item);
+        }
+
+        monster.experience = self.species.growth_rate().experience_for_level(self.level);
+        monster.species = Some(self.species);
+        monster.level = self.level;
+        monster.stats = stats;
+        monster.individual_values = self.individual_values;
+        monster.effort_values = self.effort_values;
+        monster.nature = self.nature;
+        monster.ability = ability;
+        if let Some(friendship) = self.friendship {
+            monster.friendship = friendship;
+        }
+        Ok(monster)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum MonsterBuilderError {
+    #[error("Ability {0} is not available to the species")]
+    AbilityNotAvailable(String),
+    #[error("Move {0} cannot be learned by the species")]
+    MoveNotLearnable(String),
+    #[error(transparent)]
+    Moveset(#[from] MovesetError),
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::monster::{AttackCategory, AttackPower, MonsterType, Stats};
+    use crate::moves::MoveDamage;
+    use crate::Element;
+
+    use super::*;
+
+    fn move_definition(name: &str) -> MoveDefinition {
+        MoveDefinition::new(
+            name,
+            Element::Dragon,
+            AttackCategory::Physical,
+            MoveDamage::Power(AttackPower::new(80.into())),
+            15,
+            Vec::new(),
+        )
+    }
+
+    fn species() -> Rc<Species> {
+        Rc::new(Species::new(
+            "Garchomp",
+            MonsterType::new(Element::Dragon, Some(Element::Ground)),
+            Stats::new(108, 130, 95, 80, 85, 102),
+            vec![move_definition("Dragon Claw")],
+            vec![Ability::new("Sand Veil"), Ability::new("Rough Skin")],
+        ))
+    }
+
+    fn under_test() -> MonsterBuilder {
+        MonsterBuilder::new(species(), Level::new(78))
+    }
+
+    #[test]
+    fn builds_monster_of_species_at_level() {
+        let monster = under_test().build().unwrap();
+
+        assert_that(&monster.species().unwrap().name()).is_equal_to("Garchomp");
+        assert_that(&monster.level()).is_equal_to(Level::new(78));
+        assert_that(&monster.monster_type()).is_equal_to(species().monster_type());
+    }
+
+    #[test]
+    fn computes_stats_and_max_health() {
+        let monster = under_test()
+            .individual_values(IndividualValues::new(Stats::new(24, 12, 30, 16, 23, 5)).unwrap())
+            .effort_values(EffortValues::new(Stats::new(74, 190, 91, 48, 84, 23)).unwrap())
+            .nature(Nature::Adamant)
+            .build()
+            .unwrap();
+
+        assert_that(&monster.stats()).is_equal_to(&Stats::new(289, 278, 193, 135, 171, 171));
+        assert_that(&monster.max_health()).is_equal_to(&Health::new(289.into()));
+        assert_that(&monster.health()).is_equal_to(&Health::new(289.into()));
+    }
+
+    #[test]
+    fn defaults_to_first_species_ability() {
+        assert_that(&under_test().build().unwrap().ability())
+            .is_equal_to(Some(&Ability::new("Sand Veil")));
+    }
+
+    #[test]
+    fn leaves_ability_and_moves_empty_without_defaults() {
+        let monster = under_test().without_defaults().build().unwrap();
+
+        assert_that(&monster.ability()).is_none();
+        assert_that(&monster.moveset().is_empty()).is_true();
+    }
+
+    #[test]
+    fn sets_friendship() {
+        assert_that(&under_test().friendship(255).build().unwrap().friendship()).is_equal_to(255);
+    }
+
+    #[test]
+    fn fails_to_build_with_unavailable_ability() {
+        assert_that(&under_test().ability(Ability::new("Levitate")).build().err()).is_equal_to(
+            Some(MonsterBuilderError::AbilityNotAvailable(
+                "Levitate".to_string(),
+            )),
+        );
+    }
+
+    #[test]
+    fn learns_given_moves() {
+        let monster = under_test()
+            .moves(vec![move_definition("Dragon Claw")])
+            .build()
+            .unwrap();
+
+        assert_that(&monster.moveset().knows(&move_definition("Dragon Claw"))).is_true();
+    }
+
+    #[test]
+    fn learns_latest_level_up_moves_by_default() {
+        let level_up_moves = [
+            "Sand Attack",
+            "Dragon Rage",
+            "Sandstorm",
+            "Take Down",
+            "Dig",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(index, name)| (Level::new(index as u8 * 10 + 1), move_definition(name)))
+        .collect();
+        let species = Rc::new(
+            Species::new(
+                "Gible",
+                MonsterType::new(Element::Dragon, Some(Element::Ground)),
+                Stats::new(58, 70, 45, 40, 45, 42),
+                Vec::new(),
+                Vec::new(),
+            )
+            .with_level_up_moves(level_up_moves),
+        );
+
+        let monster = MonsterBuilder::new(species, Level::new(35))
+            .build()
+            .unwrap();
+
+        let known_moves: Vec<&str> = monster
+            .moveset()
+            .slots()
+            .iter()
+            .map(|slot| slot.move_definition().name())
+            .collect();
+        assert_that(&known_moves).is_equal_to(vec![
+            "Sand Attack",
+            "Dragon Rage",
+            "Sandstorm",
+            "Take Down",
+        ]);
+    }
+
+    #[test]
+    fn starts_with_experience_for_its_level() {
+        assert_that(&under_test().build().unwrap().experience()).is_equal_to(474_552);
+    }
+
+    #[test]
+    fn fails_to_build_with_unlearnable_move() {
+        assert_that(
+            &under_test()
+                .moves(vec![move_definition("Outrage")])
+                .build()
+                .err(),
+        )
+        .is_equal_to(Some(MonsterBuilderError::MoveNotLearnable(
+            "Outrage".to_string(),
+        )));
+    }
+}
@@ -1,5 +1,6 @@
 use rust_decimal::Decimal;
 
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
 pub struct Damage(Decimal);
 
 impl Damage {
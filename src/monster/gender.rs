@@ -0,0 +1,5 @@
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Gender {
+    Male,
+    Female,
+}
@@ -0,0 +1,32 @@
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone)]
+pub struct Level(u8);
+
+impl Level {
+    pub const MAX: Level = Level(100);
+
+    pub fn new(value: u8) -> Self {
+        Level(value)
+    }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for Level {
+    fn default() -> Self {
+        Level(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn returns_integer_value() {
+        assert_that(&Level::new(50).value()).is_equal_to(50);
+    }
+}
@@ -1,46 +1,528 @@
 use std::ops::Sub;
+use std::rc::Rc;
 
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+
+pub use ability::{Ability, AbilityId};
 pub use attack::*;
+pub use builder::{MonsterBuilder, MonsterBuilderError};
 pub use damage::Damage;
+pub use gender::Gender;
 pub use health::Health;
+pub use level::Level;
 pub use monster_type::MonsterType;
+pub use moveset::{MoveSlot, Moveset, MovesetError};
+pub use nature::Nature;
+pub use stat_stages::{BattleStat, StatStages};
+pub use stat_values::{EffortValues, IndividualValues, StatValueError};
+pub use stats::{calculate_stat, calculate_stats, Stat, Stats};
+pub use status::StatusCondition;
+
+use crate::combat::ArithmeticMode;
+use crate::item::{HeldItem, ItemEffect};
+use crate::progression::LevelUp;
+use crate::species::Species;
+use crate::Element;
 
+mod ability;
 mod attack;
+mod builder;
 mod damage;
+mod gender;
 mod health;
+mod level;
 mod monster_type;
+mod moveset;
+mod nature;
+mod stat_stages;
+mod stat_values;
+mod stats;
+mod status;
+
+const BASE_FRIENDSHIP: u8 = 70;
+pub(crate) const MAX_SLEEP_TURNS: u8 = 3;
 
 pub struct Monster {
+    species: Option<Rc<Species>>,
+    nickname: Option<String>,
+    gender: Option<Gender>,
+    shiny: bool,
+    tera_type: Option<Element>,
     monster_type: MonsterType,
+    type_override: Option<MonsterType>,
     health: Health,
+    max_health: Health,
+    fainted: bool,
+    level: Level,
+    experience: u32,
+    friendship: u8,
+    stats: Stats,
+    individual_values: IndividualValues,
+    effort_values: EffortValues,
+    nature: Nature,
+    ability: Option<Ability>,
+    status: Option<StatusCondition>,
+    sleep_turns: u8,
+    stat_stages: StatStages,
+    flinched: bool,
+    moveset: Moveset,
+    held_item: Option<Box<dyn HeldItem>>,
 }
 
 impl Monster {
     pub fn new(monster_type: MonsterType, health: Health) -> Self {
         Monster {
+            species: None,
+            nickname: None,
+            gender: None,
+            shiny: false,
+            tera_type: None,
             monster_type,
+            type_override: None,
             health,
+            max_health: health,
+            fainted: health.value() <= 0.into(),
+            level: Level::default(),
+            experience: 0,
+            friendship: BASE_FRIENDSHIP,
+            stats: Stats::default(),
+            individual_values: IndividualValues::default(),
+            effort_values: EffortValues::default(),
+            nature: Nature::default(),
+            ability: None,
+            status: None,
+            sleep_turns: 0,
+            stat_stages: StatStages::new(),
+            flinched: false,
+            moveset: Moveset::new(),
+            held_item: None,
         }
     }
 
+    pub fn species(&self) -> Option<&Species> {
+        self.species.as_deref()
+    }
+
+    pub fn name(&self) -> &str {
+        self.species
+            .as_ref()
+            .map_or("Unknown monster", |species| species.name())
+    }
+
+    pub fn nickname(&self) -> Option<&str> {
+        self.nickname.as_deref()
+    }
+
+    pub fn set_nickname(&mut self, nickname: Option<&str>) {
+        self.nickname = nickname.map(str::to_string);
+    }
+
+    pub fn gender(&self) -> Option<Gender> {
+        self.gender
+    }
+
+    pub fn set_gender(&mut self, gender: Option<Gender>) {
+        self.gender = gender;
+    }
+
+    pub fn is_shiny(&self) -> bool {
+        self.shiny
+    }
+
+    pub fn set_shiny(&mut self, shiny: bool) {
+        self.shiny = shiny;
+    }
+
+    pub fn tera_type(&self) -> Option<Element> {
+        self.tera_type
+    }
+
+    pub fn set_tera_type(&mut self, tera_type: Option<Element>) {
+        self.tera_type = tera_type;
+    }
+
+    pub fn display_name(&self) -> &str {
+        self.nickname().unwrap_or_else(|| self.name())
+    }
+
     pub fn monster_type(&self) -> &MonsterType {
+        self.type_override.as_ref().unwrap_or(&self.monster_type)
+    }
+
+    pub fn base_monster_type(&self) -> &MonsterType {
         &self.monster_type
     }
 
+    pub fn has_type_override(&self) -> bool {
+        self.type_override.is_some()
+    }
+
+    pub fn add_type(&mut self, element: Element) {
+        self.type_override = Some(self.monster_type().clone().with_added_element(element));
+    }
+
+    pub fn remove_type(&mut self, element: &Element) {
+        self.type_override = Some(self.monster_type().clone().without_element(element));
+    }
+
+    pub fn replace_type(&mut self, monster_type: MonsterType) {
+        self.type_override = Some(monster_type);
+    }
+
+    pub fn clear_type_override(&mut self) {
+        self.type_override = None;
+    }
+
     pub fn receive_damage(&mut self, damage: Damage) {
-        self.health = self.health - damage;
+        let endures_hit = self
+            .held_item
+            .as_ref()
+            .is_some_and(|item| item.endures_hit(self, &damage));
+
+        if endures_hit {
+            self.held_item = None;
+            self.health = Health::new(1.into());
+        } else {
+            self.lose_health(damage);
+        }
     }
 
     pub fn health(&self) -> &Health {
         &self.health
     }
+
+    pub fn max_health(&self) -> &Health {
+        &self.max_health
+    }
+
+    pub fn is_fainted(&self) -> bool {
+        self.fainted
+    }
+
+    pub fn revive(&mut self, amount: Health) -> bool {
+        if !self.fainted {
+            return false;
+        }
+
+        let revived_value = amount.value().max(1.into()).min(self.max_health.value());
+        self.health = Health::new(revived_value);
+        self.fainted = false;
+        true
+    }
+
+    pub fn heal(&mut self, amount: Health) -> Health {
+        if self.fainted {
+            return Health::new(0.into());
+        }
+
+        let missing_value = self.max_health.value() - self.health.value();
+        let restored_value = amount.value().max(0.into()).min(missing_value);
+
+        self.health = Health::new(self.health.value() + restored_value);
+        Health::new(restored_value)
+    }
+
+    pub fn health_fraction(&self) -> Decimal {
+        if self.max_health.value() == 0.into() {
+            return 0.into();
+        }
+        self.health.value() / self.max_health.value()
+    }
+
+    pub fn health_percentage(&self) -> Decimal {
+        self.health_fraction() * Decimal::from(100)
+    }
+
+    pub fn is_at_full_health(&self) -> bool {
+        self.health == self.max_health
+    }
+
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    pub fn set_level(&mut self, level: Level) {
+        self.level = level;
+    }
+
+    pub fn experience(&self) -> u32 {
+        self.experience
+    }
+
+    pub fn gain_experience(&mut self, amount: u32) -> Vec<LevelUp> {
+        self.experience = self.experience.saturating_add(amount);
+        let species = match self.species.clone() {
+            Some(species) => species,
+            None => return Vec::new(),
+        };
+
+        let mut level_ups = Vec::new();
+        while self.level < Level::MAX {
+            let next_level = Level::new(self.level.value() + 1);
+            if self.experience < species.growth_rate().experience_for_level(next_level) {
+                break;
+            }
+            self.level = next_level;
+            level_ups.push(LevelUp::new(
+                next_level,
+                species
+                    .moves_learned_at(next_level)
+                    .into_iter()
+                    .cloned()
+                    .collect(),
+            ));
+        }
+
+        if !level_ups.is_empty() {
+            self.recalculate_stats();
+        }
+        level_ups
+    }
+
+    pub fn friendship(&self) -> u8 {
+        self.friendship
+    }
+
+    pub fn adjust_friendship(&mut self, amount: i16) {
+        self.friendship = (i16::from(self.friendship) + amount).clamp(0, 255) as u8;
+    }
+
+    pub(crate) fn change_species(&mut self, species: Rc<Species>) {
+        let ability_slot = match (&self.species, &self.ability) {
+            (Some(previous_species), Some(ability)) => previous_species
+                .abilities()
+                .iter()
+                .position(|candidate| candidate.name() == ability.name())
+                .unwrap_or(0),
+            _ => 0,
+        };
+
+        self.monster_type = species.monster_type().clone();
+        self.type_override = None;
+        self.ability = species
+            .abilities()
+            .get(ability_slot)
+            .or_else(|| species.abilities().first())
+            .cloned();
+        self.species = Some(species);
+        self.recalculate_stats();
+    }
+
+    pub(crate) fn recalculate_stats(&mut self) {
+        let species = match &self.species {
+            Some(species) => species,
+            None => return,
+        };
+
+        let previous_max_health = self.max_health.value();
+        self.stats = calculate_stats(
+            species.base_stats(),
+            &self.individual_values,
+            &self.effort_values,
+            self.level,
+            self.nature,
+        );
+        self.max_health = Health::new(self.stats.get(Stat::Hp).into());
+        if previous_max_health > 0.into() && !self.fainted {
+            self.health = Health::new(
+                (self.health.value() * self.max_health.value() / previous_max_health)
+                    .floor()
+                    .max(1.into()),
+            );
+        }
+    }
+
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
+
+    pub fn individual_values(&self) -> &IndividualValues {
+        &self.individual_values
+    }
+
+    pub fn effort_values(&self) -> &EffortValues {
+        &self.effort_values
+    }
+
+    pub fn nature(&self) -> Nature {
+        self.nature
+    }
+
+    pub fn ability(&self) -> Option<&Ability> {
+        self.ability.as_ref()
+    }
+
+    pub fn status(&self) -> Option<&StatusCondition> {
+        self.status.as_ref()
+    }
+
+    pub fn inflict_status(&mut self, status: StatusCondition) -> bool {
+        match status {
+            StatusCondition::Sleep => self.inflict_sleep(MAX_SLEEP_TURNS),
+            status => self.set_status(status),
+        }
+    }
+
+    pub fn inflict_sleep(&mut self, turns: u8) -> bool {
+        if !self.set_status(StatusCondition::Sleep) {
+            return false;
+        }
+        if self.status == Some(StatusCondition::Sleep) {
+            self.sleep_turns = turns;
+        }
+        true
+    }
+
+    fn set_status(&mut self, status: StatusCondition) -> bool {
+        if self.status.is_some() {
+            return false;
+        }
+        self.status = Some(status);
+        self.trigger_held_item(|item, holder| item.on_status_inflicted(holder));
+        true
+    }
+
+    pub fn cure_status(&mut self) {
+        self.status = None;
+        self.sleep_turns = 0;
+    }
+
+    pub fn sleep_turns(&self) -> u8 {
+        self.sleep_turns
+    }
+
+    pub(crate) fn tick_sleep(&mut self) -> bool {
+        if self.status != Some(StatusCondition::Sleep) {
+            return false;
+        }
+        if self.sleep_turns == 0 {
+            self.cure_status();
+            return false;
+        }
+        self.sleep_turns -= 1;
+        true
+    }
+
+    pub fn effective_speed(&self) -> u32 {
+        let mut speed = Decimal::from(self.stats.get(Stat::Speed))
+            * self.stat_stages.multiplier(BattleStat::Speed);
+        if let Some(item) = &self.held_item {
+            speed *= item.speed_multiplier();
+        }
+        if self.status == Some(StatusCondition::Paralysis) {
+            speed /= Decimal::from(2);
+        }
+        speed.floor().to_u32().unwrap_or(0)
+    }
+
+    pub fn stat_stages(&self) -> &StatStages {
+        &self.stat_stages
+    }
+
+    pub fn change_stat_stage(&mut self, stat: BattleStat, stages: i8) -> i8 {
+        self.stat_stages.change(stat, stages)
+    }
+
+    pub fn is_flinched(&self) -> bool {
+        self.flinched
+    }
+
+    pub fn flinch(&mut self) {
+        self.flinched = true;
+    }
+
+    pub fn moveset(&self) -> &Moveset {
+        &self.moveset
+    }
+
+    pub fn moveset_mut(&mut self) -> &mut Moveset {
+        &mut self.moveset
+    }
+
+    pub fn held_item(&self) -> Option<&dyn HeldItem> {
+        self.held_item.as_deref()
+    }
+
+    pub fn give_held_item(&mut self, item: Box<dyn HeldItem>) -> Option<Box<dyn HeldItem>> {
+        self.held_item.replace(item)
+    }
+
+    pub fn take_held_item(&mut self) -> Option<Box<dyn HeldItem>> {
+        self.held_item.take()
+    }
+
+    pub fn swap_held_items(&mut self, other: &mut Monster) {
+        std::mem::swap(&mut self.held_item, &mut other.held_item);
+    }
+
+    pub fn end_turn(&mut self) -> Vec<ItemEffect> {
+        self.end_turn_with(ArithmeticMode::default())
+    }
+
+    pub fn end_turn_with(&mut self, arithmetic_mode: ArithmeticMode) -> Vec<ItemEffect> {
+        self.flinched = false;
+        self.trigger_held_item(|item, holder| item.on_end_of_turn(holder, arithmetic_mode))
+    }
+
+    pub fn switch_out(&mut self) {
+        self.stat_stages.reset();
+        self.type_override = None;
+        self.flinched = false;
+        if let Some(item) = self.held_item.as_mut() {
+            item.on_switch_out();
+        }
+    }
+
+    pub(crate) fn trigger_held_item<F>(&mut self, trigger: F) -> Vec<ItemEffect>
+    where
+        F: FnOnce(&mut dyn HeldItem, &Monster) -> Vec<ItemEffect>,
+    {
+        let mut item = match self.held_item.take() {
+            Some(item) => item,
+            None => return Vec::new(),
+        };
+
+        let effects = trigger(item.as_mut(), self);
+        if !effects.contains(&ItemEffect::Consumed) {
+            self.held_item = Some(item);
+        }
+        effects
+            .iter()
+            .for_each(|effect| self.apply_item_effect(effect));
+        effects
+    }
+
+    fn apply_item_effect(&mut self, effect: &ItemEffect) {
+        match effect {
+            ItemEffect::Heal(amount) => {
+                self.heal(*amount);
+            }
+            ItemEffect::Recoil(damage) => self.lose_health(*damage),
+            ItemEffect::CureStatus => self.cure_status(),
+            ItemEffect::Consumed => {}
+        }
+    }
+
+    pub(crate) fn lose_health(&mut self, damage: Damage) {
+        self.health = self.health - damage;
+        if self.health.value() == 0.into() {
+            self.faint();
+        }
+    }
+
+    fn faint(&mut self) {
+        self.fainted = true;
+        self.cure_status();
+        self.flinched = false;
+    }
 }
 
 impl Sub<Damage> for Health {
     type Output = Health;
 
     fn sub(self, rhs: Damage) -> Self::Output {
-        let damage_applied_value = self.value() - rhs.value();
+        let damage_applied_value = self.value() - rhs.value().max(0.into());
 
         if damage_applied_value < 0.into() {
             Health::new(0.into())
@@ -55,6 +537,7 @@ mod tests {
     use spectral::prelude::*;
 
     use crate::elements::Element;
+    use crate::item::{ChoiceItem, FocusSash, Leftovers, LifeOrb, LumBerry};
 
     use super::*;
 
@@ -93,10 +576,209 @@ mod tests {
     }
 
     #[test]
-    fn negative_damage_adds_to_health() {
+    fn negative_damage_does_not_restore_health() {
         let mut monster = under_test();
         monster.receive_damage(Damage::new((-5).into()));
 
-        assert_that(&monster.health()).is_equal_to(&Health::new(15.into()));
+        assert_that(&monster.health()).is_equal_to(&Health::new(10.into()));
+    }
+
+    #[test]
+    fn type_override_layers_over_base_type() {
+        let mut monster = Monster::new(
+            MonsterType::new(Element::Fire, Some(Element::Flying)),
+            Health::new(10.into()),
+        );
+        monster.remove_type(&Element::Flying);
+        monster.add_type(Element::Grass);
+
+        assert_that(&monster.monster_type().elements())
+            .is_equal_to(&[Element::Fire, Element::Grass][..]);
+        assert_that(&monster.base_monster_type())
+            .is_equal_to(&MonsterType::new(Element::Fire, Some(Element::Flying)));
+    }
+
+    #[test]
+    fn switching_out_clears_type_override() {
+        let mut monster = under_test();
+        monster.replace_type(MonsterType::typeless());
+        monster.switch_out();
+
+        assert_that(&monster.has_type_override()).is_false();
+        assert_that(&monster.monster_type()).is_equal_to(&monster_type());
+    }
+
+    #[test]
+    fn faints_when_health_reaches_zero() {
+        let mut monster = under_test();
+        monster.inflict_status(StatusCondition::Burn);
+        monster.receive_damage(Damage::new(15.into()));
+
+        assert_that(&monster.is_fainted()).is_true();
+        assert_that(&monster.status()).is_none();
+    }
+
+    #[test]
+    fn fainted_monster_cannot_be_healed() {
+        let mut monster = under_test();
+        monster.receive_damage(Damage::new(10.into()));
+
+        assert_that(&monster.heal(Health::new(5.into()))).is_equal_to(Health::new(0.into()));
+        assert_that(&monster.health()).is_equal_to(&Health::new(0.into()));
+    }
+
+    #[test]
+    fn revives_fainted_monster() {
+        let mut monster = under_test();
+        monster.receive_damage(Damage::new(10.into()));
+
+        assert_that(&monster.revive(Health::new(5.into()))).is_true();
+        assert_that(&monster.is_fainted()).is_false();
+        assert_that(&monster.health()).is_equal_to(&Health::new(5.into()));
+    }
+
+    #[test]
+    fn cannot_revive_monster_that_has_not_fainted() {
+        let mut monster = under_test();
+
+        assert_that(&monster.revive(Health::new(5.into()))).is_false();
+        assert_that(&monster.health()).is_equal_to(&Health::new(10.into()));
+    }
+
+    #[test]
+    fn heals_up_to_max_health() {
+        let mut monster = under_test();
+        monster.receive_damage(Damage::new(4.into()));
+
+        assert_that(&monster.heal(Health::new(10.into()))).is_equal_to(Health::new(4.into()));
+        assert_that(&monster.health()).is_equal_to(&Health::new(10.into()));
+        assert_that(&monster.is_at_full_health()).is_true();
+    }
+
+    #[test]
+    fn negative_healing_is_ignored() {
+        let mut monster = under_test();
+        monster.receive_damage(Damage::new(4.into()));
+
+        assert_that(&monster.heal(Health::new((-3).into()))).is_equal_to(Health::new(0.into()));
+        assert_that(&monster.health()).is_equal_to(&Health::new(6.into()));
+    }
+
+    #[test]
+    fn reports_health_fraction_and_percentage() {
+        let mut monster = under_test();
+        monster.receive_damage(Damage::new(3.into()));
+
+        assert_that(&monster.health_fraction()).is_equal_to(Decimal::new(7, 1));
+        assert_that(&monster.health_percentage()).is_equal_to(Decimal::from(70));
+        assert_that(&monster.is_at_full_health()).is_false();
+    }
+
+    #[test]
+    fn cannot_be_inflicted_with_second_status() {
+        let mut monster = under_test();
+        monster.inflict_status(StatusCondition::Burn);
+
+        assert_that(&monster.inflict_status(StatusCondition::Sleep)).is_false();
+        assert_that(&monster.status()).is_equal_to(Some(&StatusCondition::Burn));
+    }
+
+    #[test]
+    fn stays_asleep_until_sleep_turns_run_out() {
+        let mut monster = under_test();
+        monster.inflict_sleep(2);
+
+        assert_that(&monster.tick_sleep()).is_true();
+        assert_that(&monster.tick_sleep()).is_true();
+        assert_that(&monster.tick_sleep()).is_false();
+        assert_that(&monster.status()).is_none();
+    }
+
+    #[test]
+    fn flinch_wears_off_at_end_of_turn() {
+        let mut monster = under_test();
+        monster.flinch();
+        monster.end_turn();
+
+        assert_that(&monster.is_flinched()).is_false();
+    }
+
+    #[test]
+    fn switching_out_resets_stat_stages() {
+        let mut monster = under_test();
+        monster.change_stat_stage(BattleStat::Attack, 2);
+        monster.switch_out();
+
+        assert_that(&monster.stat_stages().stage(BattleStat::Attack)).is_equal_to(0);
+    }
+
+    #[test]
+    fn experience_accumulates_without_species() {
+        let mut monster = under_test();
+
+        assert_that(&monster.gain_experience(500).is_empty()).is_true();
+        assert_that(&monster.experience()).is_equal_to(500);
+    }
+
+    #[test]
+    fn effective_speed_applies_stages_item_and_paralysis() {
+        let mut monster = under_test();
+        monster.stats = Stats::new(0, 0, 0, 0, 0, 100);
+        monster.change_stat_stage(BattleStat::Speed, 1);
+        monster.give_held_item(Box::new(ChoiceItem::scarf()));
+        monster.inflict_status(StatusCondition::Paralysis);
+
+        assert_that(&monster.effective_speed()).is_equal_to(112);
+    }
+
+    #[test]
+    fn held_item_can_be_taken() {
+        let mut monster = under_test();
+        monster.give_held_item(Box::new(LifeOrb::new()));
+
+        assert_that(&monster.take_held_item().unwrap().name()).is_equal_to("Life Orb");
+        assert_that(&monster.held_item().is_none()).is_true();
+    }
+
+    #[test]
+    fn held_items_can_be_swapped() {
+        let mut monster = under_test();
+        let mut other = under_test();
+        monster.give_held_item(Box::new(LifeOrb::new()));
+        other.give_held_item(Box::new(Leftovers::new()));
+        monster.swap_held_items(&mut other);
+
+        assert_that(&monster.held_item().unwrap().name()).is_equal_to("Leftovers");
+        assert_that(&other.held_item().unwrap().name()).is_equal_to("Life Orb");
+    }
+
+    #[test]
+    fn item_healing_cannot_exceed_max_health() {
+        let mut monster = Monster::new(monster_type(), Health::new(32.into()));
+        monster.give_held_item(Box::new(Leftovers::new()));
+        monster.receive_damage(Damage::new(1.into()));
+        monster.end_turn();
+
+        assert_that(&monster.health()).is_equal_to(&Health::new(32.into()));
+    }
+
+    #[test]
+    fn consumed_item_is_removed() {
+        let mut monster = under_test();
+        monster.give_held_item(Box::new(LumBerry::new()));
+        monster.inflict_status(StatusCondition::Paralysis);
+
+        assert_that(&monster.status().is_none()).is_true();
+        assert_that(&monster.held_item().is_none()).is_true();
+    }
+
+    #[test]
+    fn focus_sash_leaves_holder_with_one_health() {
+        let mut monster = under_test();
+        monster.give_held_item(Box::new(FocusSash::new()));
+        monster.receive_damage(Damage::new(15.into()));
+
+        assert_that(&monster.health()).is_equal_to(&Health::new(1.into()));
+        assert_that(&monster.held_item().is_none()).is_true();
     }
 }
@@ -1,28 +1,71 @@
+use std::fmt;
+
 use crate::elements::Element;
 
-#[derive(Debug, Eq, PartialEq)]
+const MAX_ELEMENTS: usize = 3;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
 pub struct MonsterType {
-    primary_element: Element,
-    secondary_element: Option<Element>,
+    elements: Vec<Element>,
 }
 
 impl MonsterType {
     pub fn new(primary_element: Element, secondary_element: Option<Element>) -> Self {
+        let mut elements = vec![primary_element];
+        elements.extend(secondary_element);
+        MonsterType { elements }
+    }
+
+    pub fn typeless() -> Self {
         MonsterType {
-            primary_element,
-            secondary_element,
+            elements: Vec::new(),
         }
     }
 
-    pub fn primary_element(&self) -> &Element {
-        &self.primary_element
+    pub fn primary_element(&self) -> Option<&Element> {
+        self.elements.first()
     }
 
     pub fn secondary_element(&self) -> Option<&Element> {
-        if let Some(element) = &self.secondary_element {
-            return Some(element);
+        self.elements.get(1)
+    }
+
+    pub fn elements(&self) -> &[Element] {
+        &self.elements
+    }
+
+    pub fn has_element(&self, element: &Element) -> bool {
+        self.elements.contains(element)
+    }
+
+    pub fn is_typeless(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    pub fn with_added_element(mut self, element: Element) -> Self {
+        if self.has_element(&element) {
+            return self;
+        }
+        if self.elements.len() == MAX_ELEMENTS {
+            self.elements.pop();
+        }
+        self.elements.push(element);
+        self
+    }
+
+    pub fn without_element(mut self, element: &Element) -> Self {
+        self.elements.retain(|candidate| candidate != element);
+        self
+    }
+}
+
+impl fmt::Display for MonsterType {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_typeless() {
+            return formatter.write_str("???");
         }
-        None
+        let names: Vec<String> = self.elements.iter().map(Element::to_string).collect();
+        formatter.write_str(&names.join("/"))
     }
 }
 
@@ -35,7 +78,7 @@ mod tests {
     #[test]
     fn returns_a_primary_element() {
         assert_that(&MonsterType::new(Element::Normal, None).primary_element())
-            .is_equal_to(&Element::Normal);
+            .is_equal_to(Some(&Element::Normal));
     }
 
     #[test]
@@ -57,4 +100,51 @@ mod tests {
         )
         .is_true();
     }
+
+    #[test]
+    fn typeless_type_has_no_elements() {
+        let monster_type = MonsterType::typeless();
+
+        assert_that(&monster_type.is_typeless()).is_true();
+        assert_that(&monster_type.primary_element()).is_none();
+        assert_that(&monster_type.to_string()).is_equal_to("???".to_string());
+    }
+
+    #[test]
+    fn adds_third_element() {
+        let monster_type = MonsterType::new(Element::Water, Some(Element::Ground))
+            .with_added_element(Element::Grass);
+
+        assert_that(&monster_type.elements())
+            .is_equal_to(&[Element::Water, Element::Ground, Element::Grass][..]);
+        assert_that(&monster_type.to_string()).is_equal_to("Water/Ground/Grass".to_string());
+    }
+
+    #[test]
+    fn added_element_replaces_previously_added_third_element() {
+        let monster_type = MonsterType::new(Element::Water, Some(Element::Ground))
+            .with_added_element(Element::Grass)
+            .with_added_element(Element::Ghost);
+
+        assert_that(&monster_type.elements())
+            .is_equal_to(&[Element::Water, Element::Ground, Element::Ghost][..]);
+    }
+
+    #[test]
+    fn adding_existing_element_has_no_effect() {
+        let monster_type =
+            MonsterType::new(Element::Water, None).with_added_element(Element::Water);
+
+        assert_that(&monster_type.elements()).is_equal_to(&[Element::Water][..]);
+    }
+
+    #[test]
+    fn removing_only_element_leaves_typeless() {
+        assert_that(
+            &MonsterType::new(Element::Flying, None)
+                .without_element(&Element::Flying)
+                .is_typeless(),
+        )
+        .is_true();
+    }
 }
@@ -0,0 +1,285 @@
+use crate::moves::MoveDefinition;
+
+const MAX_MOVES: usize = 4;
+const MAX_PP_UPS: u8 = 3;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct MoveSlot {
+    move_definition: MoveDefinition,
+    current_pp: u16,
+    pp_ups: u8,
+}
+
+impl MoveSlot {
+    pub fn new(move_definition: MoveDefinition) -> Self {
+        let current_pp = u16::from(move_definition.pp());
+        MoveSlot {
+            move_definition,
+            current_pp,
+            pp_ups: 0,
+        }
+    }
+
+    pub fn move_definition(&self) -> &MoveDefinition {
+        &self.move_definition
+    }
+
+    pub fn current_pp(&self) -> u16 {
+        self.current_pp
+    }
+
+    pub fn max_pp(&self) -> u16 {
+        let base_pp = u16::from(self.move_definition.pp());
+        base_pp + base_pp * u16::from(self.pp_ups) / 5
+    }
+
+    pub fn pp_ups(&self) -> u8 {
+        self.pp_ups
+    }
+
+    pub fn apply_pp_up(&mut self) -> Result<(), MovesetError> {
+        if self.pp_ups >= MAX_PP_UPS {
+            return Err(MovesetError::PowerPointsAlreadyMaximised);
+        }
+        let previous_max_pp = self.max_pp();
+        self.pp_ups += 1;
+        self.current_pp += self.max_pp() - previous_max_pp;
+        Ok(())
+    }
+
+    pub fn apply_pp_max(&mut self) -> Result<(), MovesetError> {
+        self.apply_pp_up()?;
+        while self.pp_ups < MAX_PP_UPS {
+            self.apply_pp_up()?;
+        }
+        Ok(())
+    }
+
+    pub fn deduct_pp(&mut self) {
+        self.current_pp = self.current_pp.saturating_sub(1);
+    }
+
+    pub fn restore_pp(&mut self, amount: u16) {
+        self.current_pp = self.current_pp.saturating_add(amount).min(self.max_pp());
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Moveset {
+    slots: Vec<MoveSlot>,
+}
+
+impl Moveset {
+    pub fn new() -> Self {
+        Moveset::default()
+    }
+
+    pub fn slots(&self) -> &[MoveSlot] {
+        &self.slots
+    }
+
+    pub fn slot(&self, index: usize) -> Option<&MoveSlot> {
+        self.slots.get(index)
+    }
+
+    pub fn slot_mut(&mut self, index: usize) -> Option<&mut MoveSlot> {
+        self.slots.get_mut(index)
+    }
+
+    pub fn knows(&self, move_definition: &MoveDefinition) -> bool {
+        self.slots
+            .iter()
+            .any(|slot| slot.move_definition().name() == move_definition.name())
+    }
+
+    pub fn learn(&mut self, move_definition: MoveDefinition) -> Result<(), MovesetError> {
+        if self.knows(&move_definition) {
+            return Err(MovesetError::MoveAlreadyKnown(
+                move_definition.name().to_string(),
+            ));
+        }
+        if self.slots.len() >= MAX_MOVES {
+            return Err(MovesetError::MovesetIsFull);
+        }
+        self.slots.push(MoveSlot::new(move_definition));
+        Ok(())
+    }
+
+    pub fn replace(
+        &mut self,
+        index: usize,
+        move_definition: MoveDefinition,
+    ) -> Result<MoveSlot, MovesetError> {
+        if self.knows(&move_definition) {
+            return Err(MovesetError::MoveAlreadyKnown(
+                move_definition.name().to_string(),
+            ));
+        }
+        let slot = self
+            .slots
+            .get_mut(index)
+            .ok_or(MovesetError::MoveSlotIsEmpty(index))?;
+        Ok(std::mem::replace(slot, MoveSlot::new(move_definition)))
+    }
+
+    pub fn forget(&mut self, index: usize) -> Result<MoveSlot, MovesetError> {
+        if index >= self.slots.len() {
+            return Err(MovesetError::MoveSlotIsEmpty(index));
+        }
+        Ok(self.slots.remove(index))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        !self.is_empty() && self.slots.iter().all(|slot| slot.current_pp() == 0)
+    }
+
+    pub fn restore_all_pp(&mut self) {
+        self.slots
+            .iter_mut()
+            .for_each(|slot| slot.restore_pp(slot.max_pp()));
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum MovesetError {
+    #[error("Moveset already contains the maximum number of moves")]
+    MovesetIsFull,
+    #[error("Move {0} is already known")]
+    MoveAlreadyKnown(String),
+    #[error("Move slot {0} is empty")]
+    MoveSlotIsEmpty(usize),
+    #[error("Power points are already maximised")]
+    PowerPointsAlreadyMaximised,
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::monster::{AttackCategory, AttackPower};
+    use crate::moves::MoveDamage;
+    use crate::Element;
+
+    use super::*;
+
+    fn move_definition(name: &str) -> MoveDefinition {
+        MoveDefinition::new(
+            name,
+            Element::Normal,
+            AttackCategory::Physical,
+            MoveDamage::Power(AttackPower::new(40.into())),
+            35,
+            Vec::new(),
+        )
+    }
+
+    fn full_moveset() -> Moveset {
+        let mut moveset = Moveset::new();
+        ["Tackle", "Scratch", "Pound", "Cut"]
+            .iter()
+            .for_each(|name| moveset.learn(move_definition(name)).unwrap());
+        moveset
+    }
+
+    #[test]
+    fn new_slot_has_full_pp() {
+        let slot = MoveSlot::new(move_definition("Tackle"));
+
+        assert_that(&slot.current_pp()).is_equal_to(35);
+        assert_that(&slot.max_pp()).is_equal_to(35);
+    }
+
+    #[test]
+    fn pp_cannot_be_deducted_below_zero() {
+        let mut slot = MoveSlot::new(move_definition("Tackle"));
+        (0..40).for_each(|_| slot.deduct_pp());
+
+        assert_that(&slot.current_pp()).is_equal_to(0);
+    }
+
+    #[test]
+    fn pp_up_raises_max_pp_by_a_fifth_of_base_pp() {
+        let mut slot = MoveSlot::new(move_definition("Tackle"));
+        slot.apply_pp_up().unwrap();
+
+        assert_that(&slot.max_pp()).is_equal_to(42);
+        assert_that(&slot.current_pp()).is_equal_to(42);
+    }
+
+    #[test]
+    fn pp_max_raises_max_pp_to_limit() {
+        let mut slot = MoveSlot::new(move_definition("Tackle"));
+        slot.apply_pp_max().unwrap();
+
+        assert_that(&slot.max_pp()).is_equal_to(56);
+        assert_that(&slot.apply_pp_up())
+            .is_equal_to(Err(MovesetError::PowerPointsAlreadyMaximised));
+    }
+
+    #[test]
+    fn restoring_pp_cannot_exceed_max_pp() {
+        let mut slot = MoveSlot::new(move_definition("Tackle"));
+        slot.deduct_pp();
+        slot.restore_pp(10);
+
+        assert_that(&slot.current_pp()).is_equal_to(35);
+    }
+
+    #[test]
+    fn cannot_learn_more_than_four_moves() {
+        assert_that(&full_moveset().learn(move_definition("Slam")))
+            .is_equal_to(Err(MovesetError::MovesetIsFull));
+    }
+
+    #[test]
+    fn cannot_learn_known_move() {
+        assert_that(&full_moveset().learn(move_definition("Tackle")))
+            .is_equal_to(Err(MovesetError::MoveAlreadyKnown("Tackle".to_string())));
+    }
+
+    #[test]
+    fn replaces_move_in_slot() {
+        let mut moveset = full_moveset();
+        let replaced = moveset.replace(1, move_definition("Slam")).unwrap();
+
+        assert_that(&replaced.move_definition().name()).is_equal_to("Scratch");
+        assert_that(&moveset.slot(1).unwrap().move_definition().name()).is_equal_to("Slam");
+    }
+
+    #[test]
+    fn is_exhausted_when_no_slot_has_pp() {
+        let mut moveset = Moveset::new();
+        moveset.learn(move_definition("Tackle")).unwrap();
+        (0..35).for_each(|_| moveset.slot_mut(0).unwrap().deduct_pp());
+
+        assert_that(&moveset.is_exhausted()).is_true();
+    }
+
+    #[test]
+    fn empty_moveset_is_not_exhausted() {
+        let moveset = Moveset::new();
+
+        assert_that(&moveset.is_empty()).is_true();
+        assert_that(&moveset.is_exhausted()).is_false();
+    }
+
+    #[test]
+    fn pp_max_does_not_overflow_for_high_base_pp() {
+        let mut slot = MoveSlot::new(MoveDefinition::new(
+            "Endless",
+            Element::Normal,
+            AttackCategory::Status,
+            MoveDamage::None,
+            u8::MAX,
+            Vec::new(),
+        ));
+        slot.apply_pp_max().unwrap();
+
+        assert_that(&slot.max_pp()).is_equal_to(408);
+        assert_that(&slot.current_pp()).is_equal_to(408);
+    }
+}
@@ -0,0 +1,160 @@
+use std::fmt;
+
+use crate::monster::Stat;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum Nature {
+    #[default]
+    Hardy,
+    Lonely,
+    Brave,
+    Adamant,
+    Naughty,
+    Bold,
+    Docile,
+    Relaxed,
+    Impish,
+    Lax,
+    Timid,
+    Hasty,
+    Serious,
+    Jolly,
+    Naive,
+    Modest,
+    Mild,
+    Quiet,
+    Bashful,
+    Rash,
+    Calm,
+    Gentle,
+    Sassy,
+    Careful,
+    Quirky,
+}
+
+impl Nature {
+    pub const ALL: [Nature; 25] = [
+        Nature::Hardy,
+        Nature::Lonely,
+        Nature::Brave,
+        Nature::Adamant,
+        Nature::Naughty,
+        Nature::Bold,
+        Nature::Docile,
+        Nature::Relaxed,
+        Nature::Impish,
+        Nature::Lax,
+        Nature::Timid,
+        Nature::Hasty,
+        Nature::Serious,
+        Nature::Jolly,
+        Nature::Naive,
+        Nature::Modest,
+        Nature::Mild,
+        Nature::Quiet,
+        Nature::Bashful,
+        Nature::Rash,
+        Nature::Calm,
+        Nature::Gentle,
+        Nature::Sassy,
+        Nature::Careful,
+        Nature::Quirky,
+    ];
+
+    fn affected_stats(&self) -> (Stat, Stat) {
+        match self {
+            Nature::Hardy => (Stat::Attack, Stat::Attack),
+            Nature::Lonely => (Stat::Attack, Stat::Defense),
+            Nature::Brave => (Stat::Attack, Stat::Speed),
+            Nature::Adamant => (Stat::Attack, Stat::SpecialAttack),
+            Nature::Naughty => (Stat::Attack, Stat::SpecialDefense),
+            Nature::Bold => (Stat::Defense, Stat::Attack),
+            Nature::Docile => (Stat::Defense, Stat::Defense),
+            Nature::Relaxed => (Stat::Defense, Stat::Speed),
+            Nature::Impish => (Stat::Defense, Stat::SpecialAttack),
+            Nature::Lax => (Stat::Defense, Stat::SpecialDefense),
+            Nature::Timid => (Stat::Speed, Stat::Attack),
+            Nature::Hasty => (Stat::Speed, Stat::Defense),
+            Nature::Serious => (Stat::Speed, Stat::Speed),
+            Nature::Jolly => (Stat::Speed, Stat::SpecialAttack),
+            Nature::Naive => (Stat::Speed, Stat::SpecialDefense),
+            Nature::Modest => (Stat::SpecialAttack, Stat::Attack),
+            Nature::Mild => (Stat::SpecialAttack, Stat::Defense),
+            Nature::Quiet => (Stat::SpecialAttack, Stat::Speed),
+            Nature::Bashful => (Stat::SpecialAttack, Stat::SpecialAttack),
+            Nature::Rash => (Stat::SpecialAttack, Stat::SpecialDefense),
+            Nature::Calm => (Stat::SpecialDefense, Stat::Attack),
+            Nature::Gentle => (Stat::SpecialDefense, Stat::Defense),
+            Nature::Sassy => (Stat::SpecialDefense, Stat::Speed),
+            Nature::Careful => (Stat::SpecialDefense, Stat::SpecialAttack),
+            Nature::Quirky => (Stat::SpecialDefense, Stat::SpecialDefense),
+        }
+    }
+
+    pub fn increased_stat(&self) -> Option<Stat> {
+        match self.affected_stats() {
+            (increased, decreased) if increased != decreased => Some(increased),
+            _ => None,
+        }
+    }
+
+    pub fn decreased_stat(&self) -> Option<Stat> {
+        match self.affected_stats() {
+            (increased, decreased) if increased != decreased => Some(decreased),
+            _ => None,
+        }
+    }
+
+    pub fn named(name: &str) -> Option<Nature> {
+        Nature::ALL
+            .iter()
+            .copied()
+            .find(|nature| nature.to_string().eq_ignore_ascii_case(name.trim()))
+    }
+
+    pub fn apply(&self, stat: Stat, value: u32) -> u32 {
+        if self.increased_stat() == Some(stat) {
+            value * 110 / 100
+        } else if self.decreased_stat() == Some(stat) {
+            value * 90 / 100
+        } else {
+            value
+        }
+    }
+}
+
+impl fmt::Display for Nature {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, formatter)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn raises_increased_stat_by_ten_percent() {
+        assert_that(&Nature::Modest.apply(Stat::SpecialAttack, 105)).is_equal_to(115);
+    }
+
+    #[test]
+    fn lowers_decreased_stat_by_ten_percent() {
+        assert_that(&Nature::Modest.apply(Stat::Attack, 105)).is_equal_to(94);
+    }
+
+    #[test]
+    fn neutral_nature_affects_no_stat() {
+        assert_that(&Nature::Serious.increased_stat().is_none()).is_true();
+        assert_that(&Nature::Serious.apply(Stat::Speed, 105)).is_equal_to(105);
+    }
+
+    #[test]
+    fn finds_nature_by_name() {
+        assert_that(&Nature::named("jolly")).is_equal_to(Some(Nature::Jolly));
+        assert_that(&Nature::named("Grumpy")).is_none();
+        assert_that(&Nature::Adamant.to_string()).is_equal_to("Adamant".to_string());
+    }
+}
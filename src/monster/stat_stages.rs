@@ -0,0 +1,122 @@
+use rust_decimal::Decimal;
+
+const MIN_STAGE: i8 = -6;
+const MAX_STAGE: i8 = 6;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum BattleStat {
+    Attack,
+    Defense,
+    SpecialAttack,
+    SpecialDefense,
+    Speed,
+    Accuracy,
+    Evasion,
+}
+
+impl BattleStat {
+    fn index(&self) -> usize {
+        match self {
+            BattleStat::Attack => 0,
+            BattleStat::Defense => 1,
+            BattleStat::SpecialAttack => 2,
+            BattleStat::SpecialDefense => 3,
+            BattleStat::Speed => 4,
+            BattleStat::Accuracy => 5,
+            BattleStat::Evasion => 6,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct StatStages {
+    stages: [i8; 7],
+}
+
+impl StatStages {
+    pub fn new() -> Self {
+        StatStages::default()
+    }
+
+    pub fn stage(&self, stat: BattleStat) -> i8 {
+        self.stages[stat.index()]
+    }
+
+    pub fn change(&mut self, stat: BattleStat, stages: i8) -> i8 {
+        let current = self.stage(stat);
+        let changed = current.saturating_add(stages).clamp(MIN_STAGE, MAX_STAGE);
+        self.stages[stat.index()] = changed;
+        changed - current
+    }
+
+    pub fn reset(&mut self) {
+        self.stages = [0; 7];
+    }
+
+    pub fn multiplier(&self, stat: BattleStat) -> Decimal {
+        let base = match stat {
+            BattleStat::Accuracy | BattleStat::Evasion => 3,
+            _ => 2,
+        };
+        let stage = self.stage(stat);
+
+        if stage >= 0 {
+            Decimal::from(base + stage) / Decimal::from(base)
+        } else {
+            Decimal::from(base) / Decimal::from(base - stage)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn stages_are_clamped_between_minus_six_and_six() {
+        let mut stages = StatStages::new();
+        stages.change(BattleStat::Attack, 4);
+
+        assert_that(&stages.change(BattleStat::Attack, 4)).is_equal_to(2);
+        assert_that(&stages.stage(BattleStat::Attack)).is_equal_to(6);
+    }
+
+    #[test]
+    fn extreme_changes_saturate_instead_of_overflowing() {
+        let mut stages = StatStages::new();
+        stages.change(BattleStat::Attack, 6);
+        stages.change(BattleStat::Defense, -6);
+
+        assert_that(&stages.change(BattleStat::Attack, i8::MAX)).is_equal_to(0);
+        assert_that(&stages.change(BattleStat::Defense, i8::MIN)).is_equal_to(0);
+        assert_that(&stages.change(BattleStat::Attack, i8::MIN)).is_equal_to(-12);
+        assert_that(&stages.stage(BattleStat::Attack)).is_equal_to(-6);
+    }
+
+    #[test]
+    fn positive_stages_increase_multiplier() {
+        let mut stages = StatStages::new();
+        stages.change(BattleStat::Speed, 2);
+
+        assert_that(&stages.multiplier(BattleStat::Speed)).is_equal_to(Decimal::from(2));
+    }
+
+    #[test]
+    fn negative_stages_decrease_multiplier() {
+        let mut stages = StatStages::new();
+        stages.change(BattleStat::Defense, -2);
+
+        assert_that(&stages.multiplier(BattleStat::Defense)).is_equal_to(Decimal::new(5, 1));
+    }
+
+    #[test]
+    fn reset_clears_all_stages() {
+        let mut stages = StatStages::new();
+        stages.change(BattleStat::Evasion, 1);
+        stages.reset();
+
+        assert_that(&stages).is_equal_to(StatStages::new());
+    }
+}
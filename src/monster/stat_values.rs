@@ -0,0 +1,105 @@
+use crate::monster::{Stat, Stats};
+
+const MAX_INDIVIDUAL_VALUE: u16 = 31;
+const MAX_EFFORT_VALUE: u16 = 252;
+const MAX_EFFORT_VALUE_TOTAL: u32 = 510;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct IndividualValues(Stats);
+
+impl IndividualValues {
+    pub fn new(values: Stats) -> Result<Self, StatValueError> {
+        for stat in Stat::ALL.iter() {
+            let value = values.get(*stat);
+            if value > MAX_INDIVIDUAL_VALUE {
+                return Err(StatValueError::IndividualValueOutOfRange(*stat, value));
+            }
+        }
+        Ok(IndividualValues(values))
+    }
+
+    pub fn perfect() -> Self {
+        IndividualValues(Stats::uniform(MAX_INDIVIDUAL_VALUE))
+    }
+
+    pub fn get(&self, stat: Stat) -> u16 {
+        self.0.get(stat)
+    }
+
+    pub fn values(&self) -> &Stats {
+        &self.0
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct EffortValues(Stats);
+
+impl EffortValues {
+    pub fn new(values: Stats) -> Result<Self, StatValueError> {
+        for stat in Stat::ALL.iter() {
+            let value = values.get(*stat);
+            if value > MAX_EFFORT_VALUE {
+                return Err(StatValueError::EffortValueOutOfRange(*stat, value));
+            }
+        }
+        if values.total() > MAX_EFFORT_VALUE_TOTAL {
+            return Err(StatValueError::EffortValueTotalExceeded(values.total()));
+        }
+        Ok(EffortValues(values))
+    }
+
+    pub fn get(&self, stat: Stat) -> u16 {
+        self.0.get(stat)
+    }
+
+    pub fn values(&self) -> &Stats {
+        &self.0
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, thiserror::Error)]
+pub enum StatValueError {
+    #[error("Individual value {1} for {0:?} is outside the range 0 to 31")]
+    IndividualValueOutOfRange(Stat, u16),
+    #[error("Effort value {1} for {0:?} is outside the range 0 to 252")]
+    EffortValueOutOfRange(Stat, u16),
+    #[error("Effort values total {0} exceeds the maximum of 510")]
+    EffortValueTotalExceeded(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn accepts_individual_values_up_to_thirty_one() {
+        assert_that(&IndividualValues::new(Stats::uniform(31))).is_ok();
+    }
+
+    #[test]
+    fn rejects_individual_value_above_thirty_one() {
+        assert_that(&IndividualValues::new(Stats::new(31, 31, 32, 31, 31, 31))).is_equal_to(Err(
+            StatValueError::IndividualValueOutOfRange(Stat::Defense, 32),
+        ));
+    }
+
+    #[test]
+    fn accepts_effort_values_totalling_five_hundred_and_ten() {
+        assert_that(&EffortValues::new(Stats::new(6, 252, 0, 0, 0, 252))).is_ok();
+    }
+
+    #[test]
+    fn rejects_effort_value_above_two_hundred_and_fifty_two() {
+        assert_that(&EffortValues::new(Stats::new(0, 253, 0, 0, 0, 0))).is_equal_to(Err(
+            StatValueError::EffortValueOutOfRange(Stat::Attack, 253),
+        ));
+    }
+
+    #[test]
+    fn rejects_effort_values_totalling_more_than_five_hundred_and_ten() {
+        assert_that(&EffortValues::new(Stats::new(8, 252, 0, 0, 0, 252)))
+            .is_equal_to(Err(StatValueError::EffortValueTotalExceeded(512)));
+    }
+}
@@ -0,0 +1,183 @@
+use crate::monster::{EffortValues, IndividualValues, Level, Nature};
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum Stat {
+    Hp,
+    Attack,
+    Defense,
+    SpecialAttack,
+    SpecialDefense,
+    Speed,
+}
+
+impl Stat {
+    pub const ALL: [Stat; 6] = [
+        Stat::Hp,
+        Stat::Attack,
+        Stat::Defense,
+        Stat::SpecialAttack,
+        Stat::SpecialDefense,
+        Stat::Speed,
+    ];
+
+    fn index(&self) -> usize {
+        match self {
+            Stat::Hp => 0,
+            Stat::Attack => 1,
+            Stat::Defense => 2,
+            Stat::SpecialAttack => 3,
+            Stat::SpecialDefense => 4,
+            Stat::Speed => 5,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub struct Stats {
+    values: [u16; 6],
+}
+
+impl Stats {
+    pub fn new(
+        hp: u16,
+        attack: u16,
+        defense: u16,
+        special_attack: u16,
+        special_defense: u16,
+        speed: u16,
+    ) -> Self {
+        Stats {
+            values: [hp, attack, defense, special_attack, special_defense, speed],
+        }
+    }
+
+    pub fn uniform(value: u16) -> Self {
+        Stats { values: [value; 6] }
+    }
+
+    pub fn get(&self, stat: Stat) -> u16 {
+        self.values[stat.index()]
+    }
+
+    pub fn set(&mut self, stat: Stat, value: u16) {
+        self.values[stat.index()] = value;
+    }
+
+    pub fn total(&self) -> u32 {
+        self.values.iter().map(|value| u32::from(*value)).sum()
+    }
+}
+
+pub fn calculate_stat(
+    stat: Stat,
+    base_value: u16,
+    individual_value: u16,
+    effort_value: u16,
+    level: Level,
+    nature: Nature,
+) -> u16 {
+    let level = u32::from(level.value());
+    let scaled =
+        (2 * u32::from(base_value) + u32::from(individual_value) + u32::from(effort_value) / 4)
+            * level
+            / 100;
+    let value = match stat {
+        Stat::Hp => scaled + level + 10,
+        _ => nature.apply(stat, scaled + 5),
+    };
+    value as u16
+}
+
+pub fn calculate_stats(
+    base_stats: &Stats,
+    individual_values: &IndividualValues,
+    effort_values: &EffortValues,
+    level: Level,
+    nature: Nature,
+) -> Stats {
+    let mut stats = Stats::default();
+    for stat in Stat::ALL.iter() {
+        stats.set(
+            *stat,
+            calculate_stat(
+                *stat,
+                base_stats.get(*stat),
+                individual_values.get(*stat),
+                effort_values.get(*stat),
+                level,
+                nature,
+            ),
+        );
+    }
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn returns_value_of_each_stat() {
+        let stats = Stats::new(1, 2, 3, 4, 5, 6);
+
+        assert_that(&stats.get(Stat::SpecialDefense)).is_equal_to(5);
+        assert_that(&stats.total()).is_equal_to(21);
+    }
+
+    #[test]
+    fn calculates_stats_with_standard_formula() {
+        let base_stats = Stats::new(108, 130, 95, 80, 85, 102);
+        let individual_values = IndividualValues::new(Stats::new(24, 12, 30, 16, 23, 5)).unwrap();
+        let effort_values = EffortValues::new(Stats::new(74, 190, 91, 48, 84, 23)).unwrap();
+
+        assert_that(&calculate_stats(
+            &base_stats,
+            &individual_values,
+            &effort_values,
+            Level::new(78),
+            Nature::Adamant,
+        ))
+        .is_equal_to(Stats::new(289, 278, 193, 135, 171, 171));
+    }
+
+    #[test]
+    fn calculates_hit_points_with_level_bonus() {
+        assert_that(&calculate_stat(
+            Stat::Hp,
+            45,
+            31,
+            252,
+            Level::new(100),
+            Nature::Modest,
+        ))
+        .is_equal_to(294);
+    }
+
+    #[test]
+    fn nature_does_not_affect_hit_points() {
+        assert_that(&calculate_stat(
+            Stat::Hp,
+            45,
+            31,
+            0,
+            Level::new(50),
+            Nature::Bold,
+        ))
+        .is_equal_to(120);
+    }
+
+    #[test]
+    fn applies_nature_after_flooring_base_calculation() {
+        assert_that(&calculate_stat(
+            Stat::SpecialAttack,
+            65,
+            31,
+            252,
+            Level::new(50),
+            Nature::Modest,
+        ))
+        .is_equal_to(128);
+    }
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum StatusCondition {
+    Burn,
+    Freeze,
+    Paralysis,
+    Poison,
+    BadPoison,
+    Sleep,
+}
@@ -0,0 +1,65 @@
+use crate::monster::{BattleStat, StatusCondition};
+use crate::Fraction;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub struct Chance(u8);
+
+impl Chance {
+    pub fn percent(value: u8) -> Self {
+        Chance(value.min(100))
+    }
+
+    pub fn always() -> Self {
+        Chance(100)
+    }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum EffectTarget {
+    User,
+    Target,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum HitCount {
+    Fixed(u8),
+    TwoToFive,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum MoveEffect {
+    InflictStatus {
+        status: StatusCondition,
+        chance: Chance,
+    },
+    Flinch {
+        chance: Chance,
+    },
+    StatChange {
+        target: EffectTarget,
+        stat: BattleStat,
+        stages: i8,
+        chance: Chance,
+    },
+    Recoil(Fraction),
+    SelfDamage(Fraction),
+    Drain(Fraction),
+    Heal(Fraction),
+    MultiHit(HitCount),
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn chance_cannot_exceed_one_hundred_percent() {
+        assert_that(&Chance::percent(150)).is_equal_to(Chance::always());
+    }
+}
@@ -0,0 +1,172 @@
+pub use effect::{Chance, EffectTarget, HitCount, MoveEffect};
+
+use crate::monster::{Attack, AttackCategory, AttackPower, Damage};
+use crate::{Element, Fraction};
+
+mod effect;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum MoveDamage {
+    None,
+    Power(AttackPower),
+    Fixed(Damage),
+    Level,
+    OneHitKnockOut,
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, PartialOrd, Ord, Default)]
+pub struct MoveId(u16);
+
+impl MoveId {
+    pub const fn new(value: u16) -> Self {
+        MoveId(value)
+    }
+
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct MoveDefinition {
+    id: MoveId,
+    name: String,
+    element: Element,
+    category: AttackCategory,
+    damage: MoveDamage,
+    pp: u8,
+    effects: Vec<MoveEffect>,
+}
+
+impl MoveDefinition {
+    pub fn new(
+        name: &str,
+        element: Element,
+        category: AttackCategory,
+        damage: MoveDamage,
+        pp: u8,
+        effects: Vec<MoveEffect>,
+    ) -> Self {
+        MoveDefinition {
+            id: MoveId::default(),
+            name: name.to_string(),
+            element,
+            category,
+            damage,
+            pp,
+            effects,
+        }
+    }
+
+    pub fn struggle() -> Self {
+        MoveDefinition::new(
+            "Struggle",
+            Element::Normal,
+            AttackCategory::Physical,
+            MoveDamage::Power(AttackPower::new(50.into())),
+            1,
+            vec![MoveEffect::SelfDamage(Fraction::new(1, 4))],
+        )
+    }
+
+    pub fn with_id(mut self, id: MoveId) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn id(&self) -> MoveId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn element(&self) -> &Element {
+        &self.element
+    }
+
+    pub fn category(&self) -> &AttackCategory {
+        &self.category
+    }
+
+    pub fn damage(&self) -> &MoveDamage {
+        &self.damage
+    }
+
+    pub fn pp(&self) -> u8 {
+        self.pp
+    }
+
+    pub fn effects(&self) -> &[MoveEffect] {
+        &self.effects
+    }
+
+    pub fn hit_count(&self) -> HitCount {
+        self.effects
+            .iter()
+            .find_map(|effect| match effect {
+                MoveEffect::MultiHit(hit_count) => Some(*hit_count),
+                _ => None,
+            })
+            .unwrap_or(HitCount::Fixed(1))
+    }
+
+    pub fn attack(&self) -> Attack {
+        let power = match self.damage {
+            MoveDamage::Power(power) => power,
+            _ => AttackPower::new(0.into()),
+        };
+        Attack::new(self.element, self.category, power)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    fn under_test(effects: Vec<MoveEffect>) -> MoveDefinition {
+        MoveDefinition::new(
+            "Double-Edge",
+            Element::Normal,
+            AttackCategory::Physical,
+            MoveDamage::Power(AttackPower::new(120.into())),
+            15,
+            effects,
+        )
+    }
+
+    #[test]
+    fn returns_its_name() {
+        assert_that(&under_test(Vec::new()).name()).is_equal_to("Double-Edge");
+    }
+
+    #[test]
+    fn returns_its_effects() {
+        let effects = vec![MoveEffect::Recoil(Fraction::new(1, 3))];
+
+        assert_that(&under_test(effects.clone()).effects()).is_equal_to(effects.as_slice());
+    }
+
+    #[test]
+    fn hits_once_without_multi_hit_effect() {
+        assert_that(&under_test(Vec::new()).hit_count()).is_equal_to(HitCount::Fixed(1));
+    }
+
+    #[test]
+    fn hit_count_comes_from_multi_hit_effect() {
+        assert_that(&under_test(vec![MoveEffect::MultiHit(HitCount::TwoToFive)]).hit_count())
+            .is_equal_to(HitCount::TwoToFive);
+    }
+
+    #[test]
+    fn converts_to_attack_with_its_power() {
+        assert_that(&under_test(Vec::new()).attack()).is_equal_to(Attack::new(
+            Element::Normal,
+            AttackCategory::Physical,
+            AttackPower::new(120.into()),
+        ));
+    }
+}
@@ -0,0 +1,337 @@
+use std::rc::Rc;
+
+use crate::monster::{Level, Monster};
+use crate::moves::MoveDefinition;
+use crate::species::Species;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum TimeWindow {
+    Morning,
+    Day,
+    Evening,
+    Night,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum EvolutionCondition {
+    MinimumLevel(Level),
+    UseItem(String),
+    MinimumFriendship(u8),
+    During(TimeWindow),
+    Trade,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum EvolutionTrigger {
+    LevelUp,
+    UseItem(String),
+    Trade,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Evolution {
+    into: Rc<Species>,
+    conditions: Vec<EvolutionCondition>,
+}
+
+impl Evolution {
+    pub fn new(into: Rc<Species>, conditions: Vec<EvolutionCondition>) -> Self {
+        Evolution { into, conditions }
+    }
+
+    pub fn into_species(&self) -> &Rc<Species> {
+        &self.into
+    }
+
+    pub fn conditions(&self) -> &[EvolutionCondition] {
+        &self.conditions
+    }
+
+    pub fn is_satisfied(
+        &self,
+        monster: &Monster,
+        trigger: &EvolutionTrigger,
+        time_window: TimeWindow,
+    ) -> bool {
+        let required_trigger = self
+            .conditions
+            .iter()
+            .find_map(|condition| match condition {
+                EvolutionCondition::UseItem(item) => Some(EvolutionTrigger::UseItem(item.clone())),
+                EvolutionCondition::Trade => Some(EvolutionTrigger::Trade),
+                _ => None,
+            })
+            .unwrap_or(EvolutionTrigger::LevelUp);
+        if &required_trigger != trigger {
+            return false;
+        }
+
+        self.conditions.iter().all(|condition| match condition {
+            EvolutionCondition::MinimumLevel(level) => monster.level() >= *level,
+            EvolutionCondition::MinimumFriendship(friendship) => {
+                monster.friendship() >= *friendship
+            }
+            EvolutionCondition::During(window) => *window == time_window,
+            EvolutionCondition::UseItem(_) | EvolutionCondition::Trade => true,
+        })
+    }
+}
+
+pub fn pending_evolutions<'a>(
+    monster: &'a Monster,
+    trigger: &EvolutionTrigger,
+    time_window: TimeWindow,
+) -> Vec<&'a Evolution> {
+    monster.species().map_or_else(Vec::new, |species| {
+        species
+            .evolutions()
+            .iter()
+            .filter(|evolution| evolution.is_satisfied(monster, trigger, time_window))
+            .collect()
+    })
+}
+
+pub fn evolve(
+    monster: &mut Monster,
+    evolution: &Evolution,
+    trigger: &EvolutionTrigger,
+    time_window: TimeWindow,
+) -> Result<Vec<MoveDefinition>, EvolutionError> {
+    let listed_evolution = monster.species().and_then(|species| {
+        species
+            .evolutions()
+            .iter()
+            .find(|candidate| candidate.into_species().name() == evolution.into_species().name())
+            .cloned()
+    });
+    let listed_evolution = listed_evolution.ok_or_else(|| {
+        EvolutionError::NotAnEvolutionOfSpecies(evolution.into_species().name().to_string())
+    })?;
+    if !listed_evolution.is_satisfied(monster, trigger, time_window) {
+        return Err(EvolutionError::ConditionsNotMet(
+            evolution.into_species().name().to_string(),
+        ));
+    }
+
+    let into_species = Rc::clone(listed_evolution.into_species());
+    monster.change_species(Rc::clone(&into_species));
+    Ok(into_species
+        .moves_learned_at(monster.level())
+        .into_iter()
+        .cloned()
+        .collect())
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum EvolutionError {
+    #[error("Monster cannot evolve into {0}")]
+    NotAnEvolutionOfSpecies(String),
+    #[error("Conditions to evolve into {0} are not met")]
+    ConditionsNotMet(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::monster::{
+        Ability, AttackCategory, AttackPower, Damage, Health, MonsterBuilder, MonsterType, Stats,
+    };
+    use crate::moves::MoveDamage;
+    use crate::Element;
+
+    use super::*;
+
+    fn evolved_species() -> Rc<Species> {
+        Rc::new(
+            Species::new(
+                "Ivysaur",
+                MonsterType::new(Element::Grass, Some(Element::Poison)),
+                Stats::new(60, 62, 63, 80, 80, 60),
+                Vec::new(),
+                vec![Ability::new("Overgrow"), Ability::new("Chlorophyll")],
+            )
+            .with_level_up_moves(vec![(
+                Level::new(16),
+                MoveDefinition::new(
+                    "Razor Leaf",
+                    Element::Grass,
+                    AttackCategory::Physical,
+                    MoveDamage::Power(AttackPower::new(55.into())),
+                    25,
+                    Vec::new(),
+                ),
+            )]),
+        )
+    }
+
+    fn species(conditions: Vec<EvolutionCondition>) -> Rc<Species> {
+        Rc::new(
+            Species::new(
+                "Bulbasaur",
+                MonsterType::new(Element::Grass, Some(Element::Poison)),
+                Stats::new(45, 49, 49, 65, 65, 45),
+                Vec::new(),
+                vec![Ability::new("Overgrow"), Ability::new("Chlorophyll")],
+            )
+            .with_evolutions(vec![Evolution::new(evolved_species(), conditions)]),
+        )
+    }
+
+    fn monster(conditions: Vec<EvolutionCondition>, level: u8) -> Monster {
+        MonsterBuilder::new(species(conditions), Level::new(level))
+            .ability(Ability::new("Chlorophyll"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn level_evolution_is_pending_once_level_is_reached() {
+        let monster = monster(vec![EvolutionCondition::MinimumLevel(Level::new(16))], 16);
+
+        assert_that(
+            &pending_evolutions(&monster, &EvolutionTrigger::LevelUp, TimeWindow::Day).len(),
+        )
+        .is_equal_to(1);
+    }
+
+    #[test]
+    fn level_evolution_is_not_pending_below_level() {
+        let monster = monster(vec![EvolutionCondition::MinimumLevel(Level::new(16))], 15);
+
+        assert_that(
+            &pending_evolutions(&monster, &EvolutionTrigger::LevelUp, TimeWindow::Day).is_empty(),
+        )
+        .is_true();
+    }
+
+    #[test]
+    fn item_evolution_requires_matching_item_trigger() {
+        let monster = monster(
+            vec![EvolutionCondition::UseItem("Leaf Stone".to_string())],
+            5,
+        );
+
+        assert_that(
+            &pending_evolutions(&monster, &EvolutionTrigger::LevelUp, TimeWindow::Day).is_empty(),
+        )
+        .is_true();
+        assert_that(
+            &pending_evolutions(
+                &monster,
+                &EvolutionTrigger::UseItem("Leaf Stone".to_string()),
+                TimeWindow::Day,
+            )
+            .len(),
+        )
+        .is_equal_to(1);
+    }
+
+    #[test]
+    fn friendship_evolution_respects_time_window() {
+        let mut monster = monster(
+            vec![
+                EvolutionCondition::MinimumFriendship(220),
+                EvolutionCondition::During(TimeWindow::Night),
+            ],
+            5,
+        );
+        monster.adjust_friendship(200);
+
+        assert_that(
+            &pending_evolutions(&monster, &EvolutionTrigger::LevelUp, TimeWindow::Day).is_empty(),
+        )
+        .is_true();
+        assert_that(
+            &pending_evolutions(&monster, &EvolutionTrigger::LevelUp, TimeWindow::Night).len(),
+        )
+        .is_equal_to(1);
+    }
+
+    #[test]
+    fn trade_evolution_requires_trade_trigger() {
+        let monster = monster(vec![EvolutionCondition::Trade], 5);
+
+        assert_that(&pending_evolutions(&monster, &EvolutionTrigger::Trade, TimeWindow::Day).len())
+            .is_equal_to(1);
+    }
+
+    #[test]
+    fn evolving_updates_species_type_stats_and_keeps_damage_proportional() {
+        let mut monster = monster(vec![EvolutionCondition::MinimumLevel(Level::new(16))], 16);
+        monster.receive_damage(Damage::new(20.into()));
+        let evolution = monster.species().unwrap().evolutions()[0].clone();
+
+        let moves_to_learn = evolve(
+            &mut monster,
+            &evolution,
+            &EvolutionTrigger::LevelUp,
+            TimeWindow::Day,
+        )
+        .unwrap();
+
+        assert_that(&monster.species().unwrap().name()).is_equal_to("Ivysaur");
+        assert_that(&monster.stats().get(crate::monster::Stat::Attack)).is_equal_to(24);
+        assert_that(monster.max_health()).is_equal_to(Health::new(45.into()));
+        assert_that(monster.health()).is_equal_to(Health::new(22.into()));
+        assert_that(&monster.ability()).is_equal_to(Some(&Ability::new("Chlorophyll")));
+        assert_that(&moves_to_learn[0].name()).is_equal_to("Razor Leaf");
+    }
+
+    #[test]
+    fn evolves_into_the_listed_species_rather_than_the_supplied_one() {
+        let mut monster = monster(vec![EvolutionCondition::MinimumLevel(Level::new(16))], 16);
+        let forged = Evolution::new(
+            Rc::new(Species::new(
+                "Ivysaur",
+                MonsterType::new(Element::Dragon, None),
+                Stats::uniform(255),
+                Vec::new(),
+                vec![Ability::new("Overgrow")],
+            )),
+            Vec::new(),
+        );
+
+        evolve(
+            &mut monster,
+            &forged,
+            &EvolutionTrigger::LevelUp,
+            TimeWindow::Day,
+        )
+        .unwrap();
+
+        assert_that(&monster.monster_type()).is_equal_to(evolved_species().monster_type());
+        assert_that(&monster.stats().get(crate::monster::Stat::Attack)).is_equal_to(24);
+    }
+
+    #[test]
+    fn fails_to_evolve_into_unrelated_species() {
+        let mut monster = monster(Vec::new(), 5);
+        let evolution = Evolution::new(species(Vec::new()), Vec::new());
+
+        assert_that(&evolve(
+            &mut monster,
+            &evolution,
+            &EvolutionTrigger::LevelUp,
+            TimeWindow::Day,
+        ))
+        .is_equal_to(Err(EvolutionError::NotAnEvolutionOfSpecies(
+            "Bulbasaur".to_string(),
+        )));
+    }
+
+    #[test]
+    fn fails_to_evolve_when_conditions_are_not_met() {
+        let mut monster = monster(vec![EvolutionCondition::MinimumLevel(Level::new(16))], 15);
+        let evolution = monster.species().unwrap().evolutions()[0].clone();
+
+        assert_that(&evolve(
+            &mut monster,
+            &evolution,
+            &EvolutionTrigger::LevelUp,
+            TimeWindow::Day,
+        ))
+        .is_equal_to(Err(EvolutionError::ConditionsNotMet("Ivysaur".to_string())));
+        assert_that(&monster.species().unwrap().name()).is_equal_to("Bulbasaur");
+    }
+}
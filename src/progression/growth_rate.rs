@@ -0,0 +1,87 @@
+use crate::monster::Level;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Default)]
+pub enum GrowthRate {
+    Fast,
+    #[default]
+    MediumFast,
+    MediumSlow,
+    Slow,
+    Erratic,
+    Fluctuating,
+}
+
+impl GrowthRate {
+    pub fn experience_for_level(&self, level: Level) -> u32 {
+        let n = i64::from(level.value());
+        if n <= 1 {
+            return 0;
+        }
+
+        let cube = n * n * n;
+        let experience = match self {
+            GrowthRate::Fast => 4 * cube / 5,
+            GrowthRate::MediumFast => cube,
+            GrowthRate::MediumSlow => 6 * cube / 5 - 15 * n * n + 100 * n - 140,
+            GrowthRate::Slow => 5 * cube / 4,
+            GrowthRate::Erratic => match n {
+                0..=49 => cube * (100 - n) / 50,
+                50..=67 => cube * (150 - n) / 100,
+                68..=97 => cube * ((1911 - 10 * n) / 3) / 500,
+                _ => cube * (160 - n) / 100,
+            },
+            GrowthRate::Fluctuating => match n {
+                0..=14 => cube * ((n + 1) / 3 + 24) / 50,
+                15..=35 => cube * (n + 14) / 50,
+                _ => cube * (n / 2 + 32) / 50,
+            },
+        };
+        experience.max(0) as u32
+    }
+
+    pub fn level_for_experience(&self, experience: u32) -> Level {
+        let level = (2..=Level::MAX.value())
+            .take_while(|level| self.experience_for_level(Level::new(*level)) <= experience)
+            .last()
+            .unwrap_or(1);
+        Level::new(level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn level_one_requires_no_experience() {
+        assert_that(&GrowthRate::MediumSlow.experience_for_level(Level::new(1))).is_equal_to(0);
+    }
+
+    #[test]
+    fn calculates_experience_for_level_one_hundred() {
+        let experience_at_one_hundred =
+            |growth_rate: GrowthRate| growth_rate.experience_for_level(Level::new(100));
+
+        assert_that(&experience_at_one_hundred(GrowthRate::Erratic)).is_equal_to(600_000);
+        assert_that(&experience_at_one_hundred(GrowthRate::Fast)).is_equal_to(800_000);
+        assert_that(&experience_at_one_hundred(GrowthRate::MediumFast)).is_equal_to(1_000_000);
+        assert_that(&experience_at_one_hundred(GrowthRate::MediumSlow)).is_equal_to(1_059_860);
+        assert_that(&experience_at_one_hundred(GrowthRate::Slow)).is_equal_to(1_250_000);
+        assert_that(&experience_at_one_hundred(GrowthRate::Fluctuating)).is_equal_to(1_640_000);
+    }
+
+    #[test]
+    fn calculates_experience_within_erratic_and_fluctuating_bands() {
+        assert_that(&GrowthRate::Erratic.experience_for_level(Level::new(70))).is_equal_to(276_458);
+        assert_that(&GrowthRate::Fluctuating.experience_for_level(Level::new(20)))
+            .is_equal_to(5_440);
+    }
+
+    #[test]
+    fn finds_level_for_experience() {
+        assert_that(&GrowthRate::MediumFast.level_for_experience(999)).is_equal_to(Level::new(9));
+        assert_that(&GrowthRate::MediumFast.level_for_experience(1000)).is_equal_to(Level::new(10));
+    }
+}
@@ -0,0 +1,164 @@
+pub use evolution::{
+    evolve, pending_evolutions, Evolution, EvolutionCondition, EvolutionError, EvolutionTrigger,
+    TimeWindow,
+};
+pub use growth_rate::GrowthRate;
+
+use crate::combat::BattleKind;
+use crate::monster::{Level, Monster};
+use crate::moves::MoveDefinition;
+
+mod evolution;
+mod growth_rate;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct LevelUp {
+    level: Level,
+    moves_to_learn: Vec<MoveDefinition>,
+}
+
+impl LevelUp {
+    pub fn new(level: Level, moves_to_learn: Vec<MoveDefinition>) -> Self {
+        LevelUp {
+            level,
+            moves_to_learn,
+        }
+    }
+
+    pub fn level(&self) -> Level {
+        self.level
+    }
+
+    pub fn moves_to_learn(&self) -> &[MoveDefinition] {
+        &self.moves_to_learn
+    }
+}
+
+pub fn experience_yield(defeated: &Monster, battle_kind: BattleKind) -> u32 {
+    let base_experience_yield = defeated
+        .species()
+        .map_or(0, |species| u32::from(species.base_experience_yield()));
+    let experience = base_experience_yield * u32::from(defeated.level().value()) / 7;
+
+    match battle_kind {
+        BattleKind::Wild => experience,
+        BattleKind::Trainer => experience * 3 / 2,
+    }
+}
+
+pub fn distribute_experience(
+    experience: u32,
+    participants: &mut [&mut Monster],
+) -> Vec<Vec<LevelUp>> {
+    if participants.is_empty() {
+        return Vec::new();
+    }
+
+    let share = (experience / participants.len() as u32).max(1);
+    participants
+        .iter_mut()
+        .map(|participant| participant.gain_experience(share))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use spectral::prelude::*;
+
+    use crate::monster::{
+        Ability, AttackCategory, AttackPower, Damage, Health, MonsterBuilder, MonsterType, Stats,
+    };
+    use crate::moves::MoveDamage;
+    use crate::species::Species;
+    use crate::Element;
+
+    use super::*;
+
+    fn species() -> Rc<Species> {
+        Rc::new(
+            Species::new(
+                "Pidgey",
+                MonsterType::new(Element::Normal, Some(Element::Flying)),
+                Stats::new(40, 45, 40, 35, 35, 56),
+                Vec::new(),
+                vec![Ability::new("Keen Eye")],
+            )
+            .with_base_experience_yield(50)
+            .with_level_up_moves(vec![
+                (
+                    Level::new(5),
+                    MoveDefinition::new(
+                        "Sand Attack",
+                        Element::Ground,
+                        AttackCategory::Status,
+                        MoveDamage::None,
+                        15,
+                        Vec::new(),
+                    ),
+                ),
+                (
+                    Level::new(9),
+                    MoveDefinition::new(
+                        "Gust",
+                        Element::Flying,
+                        AttackCategory::Special,
+                        MoveDamage::Power(AttackPower::new(40.into())),
+                        35,
+                        Vec::new(),
+                    ),
+                ),
+            ]),
+        )
+    }
+
+    fn monster_at(level: u8) -> Monster {
+        MonsterBuilder::new(species(), Level::new(level))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn yields_experience_based_on_species_and_level() {
+        assert_that(&experience_yield(&monster_at(14), BattleKind::Wild)).is_equal_to(100);
+    }
+
+    #[test]
+    fn trainer_battles_yield_more_experience() {
+        assert_that(&experience_yield(&monster_at(14), BattleKind::Trainer)).is_equal_to(150);
+    }
+
+    #[test]
+    fn shares_experience_evenly_among_participants() {
+        let mut first = monster_at(3);
+        let mut second = monster_at(3);
+        distribute_experience(100, &mut [&mut first, &mut second]);
+
+        assert_that(&first.experience()).is_equal_to(77);
+        assert_that(&second.experience()).is_equal_to(77);
+    }
+
+    #[test]
+    fn reports_moves_to_learn_at_each_level_gained() {
+        let mut monster = monster_at(4);
+        let level_ups = monster.gain_experience(1000 - 64);
+
+        assert_that(&level_ups.len()).is_equal_to(6);
+        assert_that(&level_ups[0].level()).is_equal_to(Level::new(5));
+        assert_that(&level_ups[0].moves_to_learn()[0].name()).is_equal_to("Sand Attack");
+        assert_that(&level_ups[4].moves_to_learn()[0].name()).is_equal_to("Gust");
+        assert_that(&level_ups[1].moves_to_learn().is_empty()).is_true();
+    }
+
+    #[test]
+    fn level_up_recalculates_stats_and_preserves_health_ratio() {
+        let mut monster = monster_at(50);
+        monster.receive_damage(Damage::new(50.into()));
+        monster.gain_experience(1_000_000);
+
+        assert_that(&monster.level()).is_equal_to(Level::MAX);
+        assert_that(monster.max_health()).is_equal_to(Health::new(190.into()));
+        assert_that(monster.health()).is_equal_to(Health::new(95.into()));
+    }
+}
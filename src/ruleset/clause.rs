@@ -0,0 +1,114 @@
+use std::fmt;
+
+use crate::monster::BattleStat;
+use crate::moves::{EffectTarget, MoveDamage, MoveDefinition, MoveEffect};
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, PartialOrd, Ord)]
+pub enum Clause {
+    Sleep,
+    Species,
+    Item,
+    OneHitKnockOut,
+    Evasion,
+    Monotype,
+}
+
+impl Clause {
+    pub fn forbids_move(&self, move_definition: &MoveDefinition) -> bool {
+        match self {
+            Clause::OneHitKnockOut => move_definition.damage() == &MoveDamage::OneHitKnockOut,
+            Clause::Evasion => move_definition.effects().iter().any(|effect| {
+                matches!(
+                    effect,
+                    MoveEffect::StatChange {
+                        target: EffectTarget::User,
+                        stat: BattleStat::Evasion,
+                        stages,
+                        ..
+                    } if *stages > 0
+                )
+            }),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Clause {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Clause::Sleep => "Sleep",
+            Clause::Species => "Species",
+            Clause::Item => "Item",
+            Clause::OneHitKnockOut => "OHKO",
+            Clause::Evasion => "Evasion",
+            Clause::Monotype => "Monotype",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::monster::{AttackCategory, Damage};
+    use crate::moves::Chance;
+    use crate::Element;
+
+    use super::*;
+
+    fn move_definition(damage: MoveDamage, effects: Vec<MoveEffect>) -> MoveDefinition {
+        MoveDefinition::new(
+            "Test Move",
+            Element::Normal,
+            AttackCategory::Status,
+            damage,
+            10,
+            effects,
+        )
+    }
+
+    #[test]
+    fn one_hit_knock_out_clause_forbids_one_hit_knock_out_moves() {
+        assert_that(
+            &Clause::OneHitKnockOut
+                .forbids_move(&move_definition(MoveDamage::OneHitKnockOut, Vec::new())),
+        )
+        .is_true();
+        assert_that(&Clause::OneHitKnockOut.forbids_move(&move_definition(
+            MoveDamage::Fixed(Damage::new(20.into())),
+            Vec::new(),
+        )))
+        .is_false();
+    }
+
+    #[test]
+    fn evasion_clause_forbids_moves_raising_user_evasion() {
+        let double_team = move_definition(
+            MoveDamage::None,
+            vec![MoveEffect::StatChange {
+                target: EffectTarget::User,
+                stat: BattleStat::Evasion,
+                stages: 1,
+                chance: Chance::always(),
+            }],
+        );
+        let sand_attack = move_definition(
+            MoveDamage::None,
+            vec![MoveEffect::StatChange {
+                target: EffectTarget::Target,
+                stat: BattleStat::Accuracy,
+                stages: -1,
+                chance: Chance::always(),
+            }],
+        );
+
+        assert_that(&Clause::Evasion.forbids_move(&double_team)).is_true();
+        assert_that(&Clause::Evasion.forbids_move(&sand_attack)).is_false();
+    }
+
+    #[test]
+    fn displays_clause_name() {
+        assert_that(&Clause::OneHitKnockOut.to_string()).is_equal_to("OHKO".to_string());
+        assert_that(&Clause::Sleep.to_string()).is_equal_to("Sleep".to_string());
+    }
+}
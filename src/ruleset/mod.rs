@@ -0,0 +1,503 @@
+use std::collections::{BTreeSet, HashSet};
+
+pub use clause::Clause;
+
+use crate::monster::{Level, Monster};
+use crate::moves::MoveDefinition;
+use crate::Element;
+
+mod clause;
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum BattleFormat {
+    Singles,
+    Doubles,
+}
+
+impl BattleFormat {
+    pub fn active_monsters_per_side(&self) -> usize {
+        match self {
+            BattleFormat::Singles => 1,
+            BattleFormat::Doubles => 2,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Ruleset {
+    name: String,
+    format: BattleFormat,
+    party_size: usize,
+    picked_party_size: Option<usize>,
+    level_cap: Option<Level>,
+    level_scaling: Option<Level>,
+    banned_species: HashSet<String>,
+    banned_moves: HashSet<String>,
+    banned_items: HashSet<String>,
+    clauses: BTreeSet<Clause>,
+}
+
+impl Ruleset {
+    pub fn new(name: &str, format: BattleFormat, party_size: usize) -> Self {
+        Ruleset {
+            name: name.to_string(),
+            format,
+            party_size,
+            picked_party_size: None,
+            level_cap: None,
+            level_scaling: None,
+            banned_species: HashSet::new(),
+            banned_moves: HashSet::new(),
+            banned_items: HashSet::new(),
+            clauses: BTreeSet::new(),
+        }
+    }
+
+    pub fn singles_six_versus_six() -> Self {
+        Ruleset::new("Singles 6v6", BattleFormat::Singles, 6)
+            .with_clause(Clause::Sleep)
+            .with_clause(Clause::Species)
+            .with_clause(Clause::OneHitKnockOut)
+            .with_clause(Clause::Evasion)
+    }
+
+    pub fn pick_three_of_six() -> Self {
+        Ruleset::new("Pick 3 of 6", BattleFormat::Singles, 6)
+            .with_picked_party_size(3)
+            .with_clause(Clause::Species)
+            .with_clause(Clause::Item)
+    }
+
+    pub fn level_fifty_doubles() -> Self {
+        Ruleset::new("Level 50 Doubles", BattleFormat::Doubles, 6)
+            .with_picked_party_size(4)
+            .with_level_scaling(Level::new(50))
+            .with_clause(Clause::Species)
+            .with_clause(Clause::Item)
+    }
+
+    pub fn monotype() -> Self {
+        Ruleset::singles_six_versus_six().with_clause(Clause::Monotype)
+    }
+
+    pub fn with_picked_party_size(mut self, picked_party_size: usize) -> Self {
+        self.picked_party_size = Some(picked_party_size);
+        self
+    }
+
+    pub fn with_level_cap(mut self, level_cap: Level) -> Self {
+        self.level_cap = Some(level_cap);
+        self
+    }
+
+    pub fn with_level_scaling(mut self, level: Level) -> Self {
+        self.level_scaling = Some(level);
+        self
+    }
+
+    pub fn with_banned_species(mut self, species_name: &str) -> Self {
+        self.banned_species.insert(species_name.to_string());
+        self
+    }
+
+    pub fn with_banned_move(mut self, move_name: &str) -> Self {
+        self.banned_moves.insert(move_name.to_string());
+        self
+    }
+
+    pub fn with_banned_item(mut self, item_name: &str) -> Self {
+        self.banned_items.insert(item_name.to_string());
+        self
+    }
+
+    pub fn with_clause(mut self, clause: Clause) -> Self {
+        self.clauses.insert(clause);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn format(&self) -> BattleFormat {
+        self.format
+    }
+
+    pub fn party_size(&self) -> usize {
+        self.party_size
+    }
+
+    pub fn picked_party_size(&self) -> usize {
+        self.picked_party_size.unwrap_or(self.party_size)
+    }
+
+    pub fn level_cap(&self) -> Option<Level> {
+        self.level_cap
+    }
+
+    pub fn level_scaling(&self) -> Option<Level> {
+        self.level_scaling
+    }
+
+    pub fn has_clause(&self, clause: Clause) -> bool {
+        self.clauses.contains(&clause)
+    }
+
+    pub fn clauses(&self) -> impl Iterator<Item = Clause> + '_ {
+        self.clauses.iter().copied()
+    }
+
+    pub fn is_species_banned(&self, species_name: &str) -> bool {
+        self.banned_species.contains(species_name)
+    }
+
+    pub fn is_item_banned(&self, item_name: &str) -> bool {
+        self.banned_items.contains(item_name)
+    }
+
+    pub fn check_move(&self, move_definition: &MoveDefinition) -> Result<(), RulesetViolation> {
+        if self.banned_moves.contains(move_definition.name()) {
+            return Err(RulesetViolation::BannedMove {
+                monster: None,
+                move_name: move_definition.name().to_string(),
+            });
+        }
+        match self
+            .clauses()
+            .find(|clause| clause.forbids_move(move_definition))
+        {
+            Some(clause) => Err(RulesetViolation::MoveForbiddenByClause {
+                monster: None,
+                move_name: move_definition.name().to_string(),
+                clause,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    pub fn apply_level_scaling(&self, monster: &mut Monster) {
+        if let Some(level) = self.level_scaling {
+            monster.set_level(level);
+            monster.recalculate_stats();
+        }
+    }
+
+    /// Checks party size, bans and the team-composition clauses (Species, Item and
+    /// Monotype). These clauses are only enforced here, not during battle.
+    pub fn validate_team(&self, team: &[Monster]) -> Result<(), Vec<RulesetViolation>> {
+        let mut violations = Vec::new();
+
+        if team.is_empty() || team.len() > self.party_size {
+            violations.push(RulesetViolation::InvalidPartySize {
+                actual: team.len(),
+                maximum: self.party_size,
+            });
+        }
+        if let Some(picked_party_size) = self.picked_party_size {
+            if team.len() < picked_party_size {
+                violations.push(RulesetViolation::NotEnoughMonstersToPick {
+                    actual: team.len(),
+                    required: picked_party_size,
+                });
+            }
+        }
+
+        for monster in team {
+            self.validate_monster(monster, &mut violations);
+        }
+
+        if self.has_clause(Clause::Species) {
+            for species_name in Self::duplicates(team.iter().map(|monster| monster.name())) {
+                violations.push(RulesetViolation::DuplicateSpecies(species_name));
+            }
+        }
+        if self.has_clause(Clause::Item) {
+            let item_names = team
+                .iter()
+                .filter_map(|monster| monster.held_item().map(|item| item.name()));
+            for item_name in Self::duplicates(item_names) {
+                violations.push(RulesetViolation::DuplicateItem(item_name));
+            }
+        }
+        if self.has_clause(Clause::Monotype) && !Self::shares_element(team) {
+            violations.push(RulesetViolation::NoSharedElement);
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    fn validate_monster(&self, monster: &Monster, violations: &mut Vec<RulesetViolation>) {
+        let monster_name = monster.name().to_string();
+        if self.is_species_banned(monster.name()) {
+            violations.push(RulesetViolation::BannedSpecies(monster_name.clone()));
+        }
+        if let Some(level_cap) = self.level_cap {
+            if monster.level() > level_cap {
+                violations.push(RulesetViolation::LevelAboveCap {
+                    monster: monster_name.clone(),
+                    level: monster.level().value(),
+                    cap: level_cap.value(),
+                });
+            }
+        }
+        if let Some(item) = monster.held_item() {
+            if self.is_item_banned(item.name()) {
+                violations.push(RulesetViolation::BannedItem {
+                    monster: monster_name.clone(),
+                    item: item.name().to_string(),
+                });
+            }
+        }
+        for move_slot in monster.moveset().slots() {
+            if let Err(violation) = self.check_move(move_slot.move_definition()) {
+                violations.push(violation.for_monster(&monster_name));
+            }
+        }
+    }
+
+    fn duplicates<'a>(names: impl Iterator<Item = &'a str>) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut duplicates = Vec::new();
+        for name in names {
+            if !seen.insert(name) && !duplicates.iter().any(|duplicate| duplicate == name) {
+                duplicates.push(name.to_string());
+            }
+        }
+        duplicates
+    }
+
+    fn shares_element(team: &[Monster]) -> bool {
+        Element::iter().any(|element| {
+            team.iter()
+                .all(|monster| monster.monster_type().has_element(&element))
+        })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, thiserror::Error)]
+pub enum RulesetViolation {
+    #[error("Team has {actual} monsters but the party size is at most {maximum}")]
+    InvalidPartySize { actual: usize, maximum: usize },
+    #[error("Team has {actual} monsters but {required} must be picked")]
+    NotEnoughMonstersToPick { actual: usize, required: usize },
+    #[error("Species is banned: {0}")]
+    BannedSpecies(String),
+    #[error("{monster} is level {level}, above the level cap of {cap}")]
+    LevelAboveCap { monster: String, level: u8, cap: u8 },
+    #[error("{monster} holds a banned item: {item}")]
+    BannedItem { monster: String, item: String },
+    #[error("Move is banned: {move_name}")]
+    BannedMove {
+        monster: Option<String>,
+        move_name: String,
+    },
+    #[error("Move {move_name} is forbidden by the {clause} clause")]
+    MoveForbiddenByClause {
+        monster: Option<String>,
+        move_name: String,
+        clause: Clause,
+    },
+    #[error("Species appears more than once: {0}")]
+    DuplicateSpecies(String),
+    #[error("Item appears more than once: {0}")]
+    DuplicateItem(String),
+    #[error("Team members do not share an element")]
+    NoSharedElement,
+}
+
+impl RulesetViolation {
+    fn for_monster(self, monster_name: &str) -> Self {
+        match self {
+            RulesetViolation::BannedMove { move_name, .. } => RulesetViolation::BannedMove {
+                monster: Some(monster_name.to_string()),
+                move_name,
+            },
+            RulesetViolation::MoveForbiddenByClause {
+                move_name, clause, ..
+            } => RulesetViolation::MoveForbiddenByClause {
+                monster: Some(monster_name.to_string()),
+                move_name,
+                clause,
+            },
+            violation => violation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use spectral::prelude::*;
+
+    use crate::item::{FocusSash, Leftovers};
+    use crate::monster::{AttackCategory, MonsterBuilder, MonsterType, Stats};
+    use crate::moves::MoveDamage;
+    use crate::species::Species;
+
+    use super::*;
+
+    fn fissure() -> MoveDefinition {
+        MoveDefinition::new(
+            "Fissure",
+            Element::Ground,
+            AttackCategory::Physical,
+            MoveDamage::OneHitKnockOut,
+            5,
+            Vec::new(),
+        )
+    }
+
+    fn species(name: &str, monster_type: MonsterType) -> Rc<Species> {
+        Rc::new(Species::new(
+            name,
+            monster_type,
+            Stats::uniform(80),
+            vec![fissure()],
+            Vec::new(),
+        ))
+    }
+
+    fn monster(name: &str, level: u8) -> Monster {
+        MonsterBuilder::new(
+            species(name, MonsterType::new(Element::Water, None)),
+            Level::new(level),
+        )
+        .build()
+        .unwrap()
+    }
+
+    #[test]
+    fn accepts_legal_team() {
+        assert_that(
+            &Ruleset::singles_six_versus_six()
+                .validate_team(&[monster("Alpha", 50), monster("Beta", 50)]),
+        )
+        .is_ok();
+    }
+
+    #[test]
+    fn reports_every_violation() {
+        let mut duplicate = monster("Alpha", 60);
+        duplicate.moveset_mut().learn(fissure()).unwrap();
+        let ruleset = Ruleset::singles_six_versus_six()
+            .with_level_cap(Level::new(55))
+            .with_banned_species("Beta");
+
+        let violations = ruleset
+            .validate_team(&[monster("Alpha", 50), duplicate, monster("Beta", 50)])
+            .unwrap_err();
+
+        assert_that(&violations).is_equal_to(vec![
+            RulesetViolation::LevelAboveCap {
+                monster: "Alpha".to_string(),
+                level: 60,
+                cap: 55,
+            },
+            RulesetViolation::MoveForbiddenByClause {
+                monster: Some("Alpha".to_string()),
+                move_name: "Fissure".to_string(),
+                clause: Clause::OneHitKnockOut,
+            },
+            RulesetViolation::BannedSpecies("Beta".to_string()),
+            RulesetViolation::DuplicateSpecies("Alpha".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn describes_clause_violation_by_clause_name() {
+        let violation = RulesetViolation::MoveForbiddenByClause {
+            monster: None,
+            move_name: "Fissure".to_string(),
+            clause: Clause::OneHitKnockOut,
+        };
+
+        assert_that(&violation.to_string())
+            .is_equal_to("Move Fissure is forbidden by the OHKO clause".to_string());
+    }
+
+    #[test]
+    fn rejects_oversized_and_undersized_parties() {
+        let team: Vec<Monster> = (0..7)
+            .map(|index| monster(&index.to_string(), 50))
+            .collect();
+
+        assert_that(&Ruleset::singles_six_versus_six().validate_team(&team)).is_equal_to(Err(
+            vec![RulesetViolation::InvalidPartySize {
+                actual: 7,
+                maximum: 6,
+            }],
+        ));
+        assert_that(&Ruleset::pick_three_of_six().validate_team(&team[..2])).is_equal_to(Err(
+            vec![RulesetViolation::NotEnoughMonstersToPick {
+                actual: 2,
+                required: 3,
+            }],
+        ));
+    }
+
+    #[test]
+    fn item_clause_rejects_duplicate_items() {
+        let mut first = monster("Alpha", 50);
+        first.give_held_item(Box::new(Leftovers::new()));
+        let mut second = monster("Beta", 50);
+        second.give_held_item(Box::new(Leftovers::new()));
+        let mut third = monster("Gamma", 50);
+        third.give_held_item(Box::new(FocusSash::new()));
+
+        assert_that(&Ruleset::pick_three_of_six().validate_team(&[first, second, third]))
+            .is_equal_to(Err(vec![RulesetViolation::DuplicateItem(
+                "Leftovers".to_string(),
+            )]));
+    }
+
+    #[test]
+    fn monotype_requires_shared_element() {
+        let fire = MonsterBuilder::new(
+            species(
+                "Fire",
+                MonsterType::new(Element::Fire, Some(Element::Water)),
+            ),
+            Level::new(50),
+        )
+        .build()
+        .unwrap();
+        let grass = MonsterBuilder::new(
+            species("Grass", MonsterType::new(Element::Grass, None)),
+            Level::new(50),
+        )
+        .build()
+        .unwrap();
+
+        assert_that(&Ruleset::monotype().validate_team(&[monster("Water", 50), fire])).is_ok();
+        assert_that(&Ruleset::monotype().validate_team(&[monster("Water", 50), grass]))
+            .is_equal_to(Err(vec![RulesetViolation::NoSharedElement]));
+    }
+
+    #[test]
+    fn scales_levels_and_stats() {
+        let mut scaled = monster("Alpha", 100);
+
+        Ruleset::level_fifty_doubles().apply_level_scaling(&mut scaled);
+
+        assert_that(&scaled.level()).is_equal_to(Level::new(50));
+        assert_that(&scaled.max_health()).is_equal_to(monster("Alpha", 50).max_health());
+    }
+
+    #[test]
+    fn checks_banned_moves() {
+        assert_that(
+            &Ruleset::new("Custom", BattleFormat::Singles, 6)
+                .with_banned_move("Fissure")
+                .check_move(&fissure()),
+        )
+        .is_equal_to(Err(RulesetViolation::BannedMove {
+            monster: None,
+            move_name: "Fissure".to_string(),
+        }));
+    }
+}
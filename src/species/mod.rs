@@ -0,0 +1,252 @@
+use std::rc::Rc;
+
+use crate::monster::{Ability, Level, MonsterType, Stats};
+use crate::moves::MoveDefinition;
+use crate::progression::{Evolution, GrowthRate};
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Hash, PartialOrd, Ord, Default)]
+pub struct SpeciesId(u16);
+
+impl SpeciesId {
+    pub const fn new(value: u16) -> Self {
+        SpeciesId(value)
+    }
+
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Species {
+    id: SpeciesId,
+    name: String,
+    monster_type: MonsterType,
+    base_stats: Stats,
+    learnable_moves: Vec<MoveDefinition>,
+    level_up_moves: Vec<(Level, MoveDefinition)>,
+    abilities: Vec<Ability>,
+    growth_rate: GrowthRate,
+    base_experience_yield: u16,
+    evolutions: Vec<Evolution>,
+    catch_rate: u8,
+}
+
+impl Species {
+    pub fn new(
+        name: &str,
+        monster_type: MonsterType,
+        base_stats: Stats,
+        learnable_moves: Vec<MoveDefinition>,
+        abilities: Vec<Ability>,
+    ) -> Self {
+        Species {
+            id: SpeciesId::default(),
+            name: name.to_string(),
+            monster_type,
+            base_stats,
+            learnable_moves,
+            level_up_moves: Vec::new(),
+            abilities,
+            growth_rate: GrowthRate::default(),
+            base_experience_yield: 0,
+            evolutions: Vec::new(),
+            catch_rate: 255,
+        }
+    }
+
+    pub fn with_id(mut self, id: SpeciesId) -> Self {
+        self.id = id;
+        self
+    }
+
+    pub fn with_catch_rate(mut self, catch_rate: u8) -> Self {
+        self.catch_rate = catch_rate;
+        self
+    }
+
+    pub fn with_evolutions(mut self, evolutions: Vec<Evolution>) -> Self {
+        self.evolutions = evolutions;
+        self
+    }
+
+    pub fn with_level_up_moves(mut self, level_up_moves: Vec<(Level, MoveDefinition)>) -> Self {
+        self.level_up_moves = level_up_moves;
+        self
+    }
+
+    pub fn with_growth_rate(mut self, growth_rate: GrowthRate) -> Self {
+        self.growth_rate = growth_rate;
+        self
+    }
+
+    pub fn with_base_experience_yield(mut self, base_experience_yield: u16) -> Self {
+        self.base_experience_yield = base_experience_yield;
+        self
+    }
+
+    pub fn id(&self) -> SpeciesId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn monster_type(&self) -> &MonsterType {
+        &self.monster_type
+    }
+
+    pub fn base_stats(&self) -> &Stats {
+        &self.base_stats
+    }
+
+    pub fn learnable_moves(&self) -> &[MoveDefinition] {
+        &self.learnable_moves
+    }
+
+    pub fn level_up_moves(&self) -> &[(Level, MoveDefinition)] {
+        &self.level_up_moves
+    }
+
+    pub fn moves_learned_at(&self, level: Level) -> Vec<&MoveDefinition> {
+        self.level_up_moves
+            .iter()
+            .filter(|(learned_at, _)| *learned_at == level)
+            .map(|(_, move_definition)| move_definition)
+            .collect()
+    }
+
+    pub fn growth_rate(&self) -> GrowthRate {
+        self.growth_rate
+    }
+
+    pub fn base_experience_yield(&self) -> u16 {
+        self.base_experience_yield
+    }
+
+    pub fn catch_rate(&self) -> u8 {
+        self.catch_rate
+    }
+
+    pub fn evolutions(&self) -> &[Evolution] {
+        &self.evolutions
+    }
+
+    pub fn abilities(&self) -> &[Ability] {
+        &self.abilities
+    }
+
+    pub fn can_learn(&self, move_definition: &MoveDefinition) -> bool {
+        self.learnable_moves
+            .iter()
+            .chain(
+                self.level_up_moves
+                    .iter()
+                    .map(|(_, learnable_move)| learnable_move),
+            )
+            .any(|learnable_move| learnable_move.name() == move_definition.name())
+    }
+
+    pub fn has_ability(&self, ability: &Ability) -> bool {
+        self.ability_named(ability.name()).is_some()
+    }
+
+    pub fn ability_named(&self, name: &str) -> Option<&Ability> {
+        self.abilities
+            .iter()
+            .find(|candidate| candidate.name() == name)
+    }
+}
+
+pub trait SpeciesLookup {
+    fn species_named(&self, name: &str) -> Option<Rc<Species>>;
+
+    fn species_with_id(&self, id: SpeciesId) -> Option<Rc<Species>>;
+}
+
+impl SpeciesLookup for [Rc<Species>] {
+    fn species_named(&self, name: &str) -> Option<Rc<Species>> {
+        self.iter()
+            .find(|species| species.name().eq_ignore_ascii_case(name.trim()))
+            .cloned()
+    }
+
+    fn species_with_id(&self, id: SpeciesId) -> Option<Rc<Species>> {
+        self.iter().find(|species| species.id() == id).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use spectral::prelude::*;
+
+    use crate::monster::{AttackCategory, AttackPower};
+    use crate::moves::MoveDamage;
+    use crate::Element;
+
+    use super::*;
+
+    fn tackle() -> MoveDefinition {
+        MoveDefinition::new(
+            "Tackle",
+            Element::Normal,
+            AttackCategory::Physical,
+            MoveDamage::Power(AttackPower::new(40.into())),
+            35,
+            Vec::new(),
+        )
+    }
+
+    fn under_test() -> Species {
+        Species::new(
+            "Bulbasaur",
+            MonsterType::new(Element::Grass, Some(Element::Poison)),
+            Stats::new(45, 49, 49, 65, 65, 45),
+            vec![tackle()],
+            vec![Ability::new("Overgrow")],
+        )
+    }
+
+    #[test]
+    fn can_learn_its_learnable_moves() {
+        assert_that(&under_test().can_learn(&tackle())).is_true();
+    }
+
+    #[test]
+    fn cannot_learn_other_moves() {
+        assert_that(&under_test().can_learn(&MoveDefinition::struggle())).is_false();
+    }
+
+    #[test]
+    fn can_learn_its_level_up_moves() {
+        let species =
+            under_test().with_level_up_moves(vec![(Level::new(7), MoveDefinition::struggle())]);
+
+        assert_that(&species.can_learn(&MoveDefinition::struggle())).is_true();
+        assert_that(&species.moves_learned_at(Level::new(7)).len()).is_equal_to(1);
+        assert_that(&species.moves_learned_at(Level::new(8)).is_empty()).is_true();
+    }
+
+    #[test]
+    fn has_its_abilities() {
+        assert_that(&under_test().has_ability(&Ability::new("Overgrow"))).is_true();
+        assert_that(&under_test().has_ability(&Ability::new("Blaze"))).is_false();
+    }
+
+    #[test]
+    fn looks_up_species_by_name() {
+        let species = [Rc::new(under_test())];
+
+        assert_that(&species[..].species_named("bulbasaur").is_some()).is_true();
+        assert_that(&species[..].species_named("Ivysaur").is_none()).is_true();
+    }
+
+    #[test]
+    fn looks_up_species_by_id() {
+        let species = [Rc::new(under_test().with_id(SpeciesId::new(1)))];
+
+        assert_that(&species[..].species_with_id(SpeciesId::new(1)).is_some()).is_true();
+        assert_that(&species[..].species_with_id(SpeciesId::new(2)).is_none()).is_true();
+    }
+}
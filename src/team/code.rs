@@ -0,0 +1,669 @@
+use crate::item::{held_item_id, held_item_with_id, ItemId};
+use crate::monster::{
+    Ability, AbilityId, EffortValues, Gender, IndividualValues, Level, Monster, MonsterBuilder,
+    MonsterBuilderError, Nature, Stat, StatValueError, Stats,
+};
+use crate::moves::{MoveDefinition, MoveId};
+use crate::species::{Species, SpeciesId, SpeciesLookup};
+use crate::team::Team;
+use crate::Element;
+
+const VERSION: u8 = 1;
+const CHECKSUM_LENGTH: usize = 2;
+const NO_GENDER: u8 = 0;
+const MALE: u8 = 1;
+const FEMALE: u8 = 2;
+const NO_TERA_TYPE: u8 = 0;
+const NO_ABILITY: u16 = 0;
+const NO_ITEM: u16 = 0;
+const NATURE_CODES: [(Nature, u8); 25] = [
+    (Nature::Hardy, 0),
+    (Nature::Lonely, 1),
+    (Nature::Brave, 2),
+    (Nature::Adamant, 3),
+    (Nature::Naughty, 4),
+    (Nature::Bold, 5),
+    (Nature::Docile, 6),
+    (Nature::Relaxed, 7),
+    (Nature::Impish, 8),
+    (Nature::Lax, 9),
+    (Nature::Timid, 10),
+    (Nature::Hasty, 11),
+    (Nature::Serious, 12),
+    (Nature::Jolly, 13),
+    (Nature::Naive, 14),
+    (Nature::Modest, 15),
+    (Nature::Mild, 16),
+    (Nature::Quiet, 17),
+    (Nature::Bashful, 18),
+    (Nature::Rash, 19),
+    (Nature::Calm, 20),
+    (Nature::Gentle, 21),
+    (Nature::Sassy, 22),
+    (Nature::Careful, 23),
+    (Nature::Quirky, 24),
+];
+const ELEMENT_CODES: [(Element, u8); 18] = [
+    (Element::Bug, 1),
+    (Element::Dark, 2),
+    (Element::Dragon, 3),
+    (Element::Electric, 4),
+    (Element::Fairy, 5),
+    (Element::Fighting, 6),
+    (Element::Fire, 7),
+    (Element::Flying, 8),
+    (Element::Ghost, 9),
+    (Element::Grass, 10),
+    (Element::Ground, 11),
+    (Element::Ice, 12),
+    (Element::Normal, 13),
+    (Element::Poison, 14),
+    (Element::Psychic, 15),
+    (Element::Rock, 16),
+    (Element::Steel, 17),
+    (Element::Water, 18),
+];
+const INDIVIDUAL_VALUE_BITS: u32 = 5;
+const INDIVIDUAL_VALUE_MASK: u32 = 0b11111;
+
+pub fn encode_team(team: &Team) -> Result<String, TeamCodeError> {
+    if team.len() > usize::from(u8::MAX) {
+        return Err(TeamCodeError::TooManyMembers(team.len()));
+    }
+
+    let mut bytes = vec![VERSION, team.len() as u8];
+    for (slot, monster) in team.members().iter().enumerate() {
+        encode_monster(slot, monster, &mut bytes)?;
+    }
+    let checksum = checksum(&bytes);
+    bytes.extend_from_slice(&checksum.to_be_bytes());
+
+    Ok(base64::encode_config(bytes, base64::URL_SAFE_NO_PAD))
+}
+
+pub fn decode_team<L: SpeciesLookup + ?Sized>(
+    code: &str,
+    species_lookup: &L,
+) -> Result<Team, TeamCodeError> {
+    let bytes = base64::decode_config(code.trim(), base64::URL_SAFE_NO_PAD)
+        .map_err(|_| TeamCodeError::InvalidEncoding)?;
+    match bytes.first() {
+        None => return Err(TeamCodeError::Truncated),
+        Some(version) if *version != VERSION => {
+            return Err(TeamCodeError::UnsupportedVersion(*version))
+        }
+        Some(_) => {}
+    }
+    if bytes.len() < 1 + CHECKSUM_LENGTH {
+        return Err(TeamCodeError::Truncated);
+    }
+
+    let (payload, expected) = bytes.split_at(bytes.len() - CHECKSUM_LENGTH);
+    if checksum(payload).to_be_bytes() != expected {
+        return Err(TeamCodeError::ChecksumMismatch);
+    }
+
+    let mut reader = Reader::new(&payload[1..]);
+    let member_count = reader.byte()?;
+    let mut team = Team::default();
+    for _ in 0..member_count {
+        team.add_member(decode_monster(&mut reader, species_lookup)?);
+    }
+    if !reader.is_exhausted() {
+        return Err(TeamCodeError::TrailingData);
+    }
+
+    Ok(team)
+}
+
+fn encode_monster(
+    slot: usize,
+    monster: &Monster,
+    bytes: &mut Vec<u8>,
+) -> Result<(), TeamCodeError> {
+    let species = monster
+        .species()
+        .ok_or(TeamCodeError::MissingSpecies { slot })?;
+    if species.id() == SpeciesId::default() {
+        return Err(TeamCodeError::UnassignedSpeciesId {
+            slot,
+            species: species.name().to_string(),
+        });
+    }
+    let ability = match monster.ability() {
+        Some(ability) if ability.id() == AbilityId::default() => {
+            return Err(TeamCodeError::UnencodableAbility {
+                slot,
+                ability: ability.name().to_string(),
+            })
+        }
+        Some(ability) => ability.id().value(),
+        None => NO_ABILITY,
+    };
+    let item = match monster.held_item() {
+        Some(held_item) => held_item_id(held_item)
+            .map(|id| id.value())
+            .ok_or_else(|| TeamCodeError::UnencodableItem {
+                slot,
+                item: held_item.name().to_string(),
+            })?,
+        None => NO_ITEM,
+    };
+    let tera_type = match monster.tera_type() {
+        Some(tera_type) => {
+            element_code(tera_type).ok_or(TeamCodeError::UnencodableTeraType { slot, tera_type })?
+        }
+        None => NO_TERA_TYPE,
+    };
+    let mut move_ids = Vec::new();
+    for move_slot in monster.moveset().slots() {
+        let move_definition = move_slot.move_definition();
+        if move_definition.id() == MoveId::default() {
+            return Err(TeamCodeError::UnassignedMoveId {
+                slot,
+                move_name: move_definition.name().to_string(),
+            });
+        }
+        move_ids.push(move_definition.id().value());
+    }
+    let nickname = monster.nickname().unwrap_or_default().as_bytes();
+    if nickname.len() > usize::from(u8::MAX) {
+        return Err(TeamCodeError::NicknameTooLong { slot });
+    }
+
+    bytes.extend_from_slice(&species.id().value().to_be_bytes());
+    bytes.push(monster.level().value());
+    bytes.push(nature_code(monster.nature()));
+    bytes.push(monster.friendship());
+    bytes.push(match monster.gender() {
+        None => NO_GENDER,
+        Some(Gender::Male) => MALE,
+        Some(Gender::Female) => FEMALE,
+    });
+    bytes.push(u8::from(monster.is_shiny()));
+    bytes.push(tera_type);
+    bytes.extend_from_slice(&ability.to_be_bytes());
+    bytes.extend_from_slice(&item.to_be_bytes());
+    for stat in Stat::ALL.iter() {
+        bytes.push(monster.effort_values().get(*stat) as u8);
+    }
+    bytes.extend_from_slice(&pack_individual_values(monster.individual_values()).to_be_bytes());
+    bytes.push(move_ids.len() as u8);
+    for move_id in move_ids {
+        bytes.extend_from_slice(&move_id.to_be_bytes());
+    }
+    bytes.push(nickname.len() as u8);
+    bytes.extend_from_slice(nickname);
+
+    Ok(())
+}
+
+fn decode_monster<L: SpeciesLookup + ?Sized>(
+    reader: &mut Reader,
+    species_lookup: &L,
+) -> Result<Monster, TeamCodeError> {
+    let species_id = SpeciesId::new(reader.u16()?);
+    let species = species_lookup
+        .species_with_id(species_id)
+        .ok_or_else(|| TeamCodeError::UnknownSpecies(species_id.value()))?;
+    let level = match reader.byte()? {
+        level if level >= 1 && level <= Level::MAX.value() => Level::new(level),
+        level => return Err(TeamCodeError::InvalidLevel(level)),
+    };
+    let nature_code = reader.byte()?;
+    let nature = NATURE_CODES
+        .iter()
+        .find(|(_, code)| *code == nature_code)
+        .map(|(nature, _)| *nature)
+        .ok_or(TeamCodeError::UnknownNature(nature_code))?;
+    let friendship = reader.byte()?;
+    let gender = match reader.byte()? {
+        NO_GENDER => None,
+        MALE => Some(Gender::Male),
+        FEMALE => Some(Gender::Female),
+        code => return Err(TeamCodeError::UnknownGender(code)),
+    };
+    let shiny = match reader.byte()? {
+        0 => false,
+        1 => true,
+        flag => return Err(TeamCodeError::InvalidShinyFlag(flag)),
+    };
+    let tera_type = match reader.byte()? {
+        NO_TERA_TYPE => None,
+        code => Some(
+            ELEMENT_CODES
+                .iter()
+                .find(|(_, candidate)| *candidate == code)
+                .map(|(element, _)| *element)
+                .ok_or(TeamCodeError::UnknownTeraType(code))?,
+        ),
+    };
+    let ability = match reader.u16()? {
+        NO_ABILITY => None,
+        id => Some(
+            find_ability(&species, AbilityId::new(id)).ok_or(TeamCodeError::UnknownAbility(id))?,
+        ),
+    };
+    let item = match reader.u16()? {
+        NO_ITEM => None,
+        id => Some(held_item_with_id(ItemId::new(id)).ok_or(TeamCodeError::UnknownItem(id))?),
+    };
+    let mut effort_values = Stats::uniform(0);
+    for stat in Stat::ALL.iter() {
+        effort_values.set(*stat, u16::from(reader.byte()?));
+    }
+    let individual_values = unpack_individual_values(reader.u32()?);
+    let mut moves = Vec::new();
+    for _ in 0..reader.byte()? {
+        let move_id = MoveId::new(reader.u16()?);
+        moves.push(
+            find_move(&species, move_id).ok_or_else(|| TeamCodeError::UnknownMove {
+                species: species.name().to_string(),
+                move_id: move_id.value(),
+            })?,
+        );
+    }
+    let nickname_length = reader.byte()?;
+    let nickname = std::str::from_utf8(reader.take(usize::from(nickname_length))?)
+        .map_err(|_| TeamCodeError::InvalidNickname)?;
+
+    let mut builder = MonsterBuilder::new(species, level)
+        .individual_values(IndividualValues::new(individual_values)?)
+        .effort_values(EffortValues::new(effort_values)?)
+        .nature(nature)
+        .friendship(friendship)
+        .moves(moves);
+    if let Some(ability) = ability {
+        builder = builder.ability(ability);
+    }
+    if let Some(item) = item {
+        builder = builder.held_item(item);
+    }
+
+    let mut monster = builder.build()?;
+    if !nickname.is_empty() {
+        monster.set_nickname(Some(nickname));
+    }
+    monster.set_gender(gender);
+    monster.set_shiny(shiny);
+    monster.set_tera_type(tera_type);
+    Ok(monster)
+}
+
+fn find_move(species: &Species, move_id: MoveId) -> Option<MoveDefinition> {
+    species
+        .learnable_moves()
+        .iter()
+        .chain(
+            species
+                .level_up_moves()
+                .iter()
+                .map(|(_, move_definition)| move_definition),
+        )
+        .find(|move_definition| move_definition.id() == move_id)
+        .cloned()
+}
+
+fn find_ability(species: &Species, ability_id: AbilityId) -> Option<Ability> {
+    species
+        .abilities()
+        .iter()
+        .find(|ability| ability.id() == ability_id)
+        .cloned()
+}
+
+fn nature_code(nature: Nature) -> u8 {
+    NATURE_CODES
+        .iter()
+        .find(|(candidate, _)| *candidate == nature)
+        .map(|(_, code)| *code)
+        .unwrap_or_default()
+}
+
+fn element_code(element: Element) -> Option<u8> {
+    ELEMENT_CODES
+        .iter()
+        .find(|(candidate, _)| *candidate == element)
+        .map(|(_, code)| *code)
+}
+
+fn pack_individual_values(individual_values: &IndividualValues) -> u32 {
+    Stat::ALL.iter().fold(0, |packed, stat| {
+        (packed << INDIVIDUAL_VALUE_BITS) | u32::from(individual_values.get(*stat))
+    })
+}
+
+fn unpack_individual_values(packed: u32) -> Stats {
+    let mut values = Stats::uniform(0);
+    for (index, stat) in Stat::ALL.iter().rev().enumerate() {
+        let value = (packed >> (index as u32 * INDIVIDUAL_VALUE_BITS)) & INDIVIDUAL_VALUE_MASK;
+        values.set(*stat, value as u16);
+    }
+    values
+}
+
+fn checksum(bytes: &[u8]) -> u16 {
+    let (low, high) = bytes.iter().fold((0u16, 0u16), |(low, high), byte| {
+        let low = (low + u16::from(*byte)) % 255;
+        (low, (high + low) % 255)
+    });
+    (high << 8) | low
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, position: 0 }
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], TeamCodeError> {
+        let end = self.position + length;
+        let bytes = self
+            .bytes
+            .get(self.position..end)
+            .ok_or(TeamCodeError::Truncated)?;
+        self.position = end;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, TeamCodeError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, TeamCodeError> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, TeamCodeError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.position == self.bytes.len()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum TeamCodeError {
+    #[error("A team code cannot hold {0} monsters")]
+    TooManyMembers(usize),
+    #[error("Monster in slot {slot} has no species")]
+    MissingSpecies { slot: usize },
+    #[error("Species {species} of the monster in slot {slot} has no id")]
+    UnassignedSpeciesId { slot: usize, species: String },
+    #[error("Move {move_name} of the monster in slot {slot} has no id")]
+    UnassignedMoveId { slot: usize, move_name: String },
+    #[error("Ability {ability} of the monster in slot {slot} cannot be encoded")]
+    UnencodableAbility { slot: usize, ability: String },
+    #[error("Item {item} of the monster in slot {slot} cannot be encoded")]
+    UnencodableItem { slot: usize, item: String },
+    #[error("Tera type {tera_type} of the monster in slot {slot} cannot be encoded")]
+    UnencodableTeraType { slot: usize, tera_type: Element },
+    #[error("Nickname of the monster in slot {slot} is too long")]
+    NicknameTooLong { slot: usize },
+    #[error("Team code is not valid base64")]
+    InvalidEncoding,
+    #[error("Team code version {0} is not supported")]
+    UnsupportedVersion(u8),
+    #[error("Team code checksum does not match")]
+    ChecksumMismatch,
+    #[error("Team code ended unexpectedly")]
+    Truncated,
+    #[error("Team code has unexpected trailing data")]
+    TrailingData,
+    #[error("Species {0} is unknown")]
+    UnknownSpecies(u16),
+    #[error("Level {0} is out of range")]
+    InvalidLevel(u8),
+    #[error("Nature {0} is unknown")]
+    UnknownNature(u8),
+    #[error("Gender {0} is unknown")]
+    UnknownGender(u8),
+    #[error("Shiny flag {0} is not valid")]
+    InvalidShinyFlag(u8),
+    #[error("Tera type {0} is unknown")]
+    UnknownTeraType(u8),
+    #[error("Ability {0} is unknown")]
+    UnknownAbility(u16),
+    #[error("Item {0} is unknown")]
+    UnknownItem(u16),
+    #[error("Move {move_id} is unknown to {species}")]
+    UnknownMove { species: String, move_id: u16 },
+    #[error("Nickname is not valid UTF-8")]
+    InvalidNickname,
+    #[error(transparent)]
+    InvalidStatValues(#[from] StatValueError),
+    #[error(transparent)]
+    InvalidMonster(#[from] MonsterBuilderError),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use spectral::prelude::*;
+
+    use crate::item::ChoiceItem;
+    use crate::monster::{AttackCategory, AttackPower, Health, MonsterType};
+    use crate::moves::MoveDamage;
+    use crate::ElementId;
+
+    use super::*;
+
+    const TEAM_CODE: &str = "AQEAhzIKRgIBCAAKAAIEAAD8APw-D_v_AgBVAGIGU3Bhcmt5SaY";
+
+    fn move_definition(id: u16, name: &str) -> MoveDefinition {
+        MoveDefinition::new(
+            name,
+            Element::Electric,
+            AttackCategory::Special,
+            MoveDamage::Power(AttackPower::new(90.into())),
+            15,
+            Vec::new(),
+        )
+        .with_id(MoveId::new(id))
+    }
+
+    fn species() -> Vec<Rc<Species>> {
+        vec![Rc::new(
+            Species::new(
+                "Volteon",
+                MonsterType::new(Element::Electric, None),
+                Stats::new(65, 65, 60, 110, 95, 130),
+                vec![
+                    move_definition(85, "Thunderbolt"),
+                    move_definition(98, "Quick Attack"),
+                ],
+                vec![
+                    Ability::new("Static").with_id(AbilityId::new(9)),
+                    Ability::new("Volt Absorb").with_id(AbilityId::new(10)),
+                ],
+            )
+            .with_id(SpeciesId::new(135)),
+        )]
+    }
+
+    fn team() -> Team {
+        let species = species();
+        let mut monster = MonsterBuilder::new(species[0].clone(), Level::new(50))
+            .individual_values(IndividualValues::new(Stats::new(31, 0, 31, 30, 31, 31)).unwrap())
+            .effort_values(EffortValues::new(Stats::new(4, 0, 0, 252, 0, 252)).unwrap())
+            .nature(Nature::Timid)
+            .ability(Ability::new("Volt Absorb"))
+            .moves(vec![
+                move_definition(85, "Thunderbolt"),
+                move_definition(98, "Quick Attack"),
+            ])
+            .held_item(Box::new(ChoiceItem::specs()))
+            .build()
+            .unwrap();
+        monster.set_nickname(Some("Sparky"));
+        monster.set_gender(Some(Gender::Female));
+        monster.set_shiny(true);
+        monster.set_tera_type(Some(Element::Flying));
+        Team::new(vec![monster])
+    }
+
+    fn assert_same_monster(actual: &Monster, expected: &Monster) {
+        assert_that(&actual.species()).is_equal_to(expected.species());
+        assert_that(&actual.nickname()).is_equal_to(expected.nickname());
+        assert_that(&actual.level()).is_equal_to(expected.level());
+        assert_that(&actual.nature()).is_equal_to(expected.nature());
+        assert_that(&actual.friendship()).is_equal_to(expected.friendship());
+        assert_that(&actual.gender()).is_equal_to(expected.gender());
+        assert_that(&actual.is_shiny()).is_equal_to(expected.is_shiny());
+        assert_that(&actual.tera_type()).is_equal_to(expected.tera_type());
+        assert_that(&actual.ability()).is_equal_to(expected.ability());
+        assert_that(&actual.held_item().map(|item| item.name().to_string()))
+            .is_equal_to(expected.held_item().map(|item| item.name().to_string()));
+        assert_that(actual.effort_values()).is_equal_to(expected.effort_values());
+        assert_that(actual.individual_values()).is_equal_to(expected.individual_values());
+        assert_that(actual.moveset()).is_equal_to(expected.moveset());
+    }
+
+    #[test]
+    fn round_trips_a_team() {
+        let team = team();
+
+        let decoded = decode_team(&encode_team(&team).unwrap(), &species()[..]).unwrap();
+
+        assert_that(&decoded.len()).is_equal_to(1);
+        assert_same_monster(&decoded.members()[0], &team.members()[0]);
+    }
+
+    #[test]
+    fn decodes_a_known_code() {
+        let decoded = decode_team(TEAM_CODE, &species()[..]).unwrap();
+
+        assert_same_monster(&decoded.members()[0], &team().members()[0]);
+    }
+
+    #[test]
+    fn encoding_is_stable() {
+        assert_that(&encode_team(&team()).unwrap()).is_equal_to(TEAM_CODE.to_string());
+    }
+
+    #[test]
+    fn rejects_corrupted_codes() {
+        let mut bytes = base64::decode_config(TEAM_CODE, base64::URL_SAFE_NO_PAD).unwrap();
+        bytes[3] ^= 1;
+        let corrupted = base64::encode_config(bytes, base64::URL_SAFE_NO_PAD);
+
+        assert_that(&decode_team(&corrupted, &species()[..]).err())
+            .is_equal_to(Some(TeamCodeError::ChecksumMismatch));
+    }
+
+    #[test]
+    fn rejects_unsupported_versions() {
+        let code = base64::encode_config([2, 0, 0, 0], base64::URL_SAFE_NO_PAD);
+
+        assert_that(&decode_team(&code, &species()[..]).err())
+            .is_equal_to(Some(TeamCodeError::UnsupportedVersion(2)));
+    }
+
+    #[test]
+    fn rejects_invalid_and_truncated_codes() {
+        let truncated = &TEAM_CODE[..TEAM_CODE.len() - 4];
+
+        assert_that(&decode_team("not base64!", &species()[..]).err())
+            .is_equal_to(Some(TeamCodeError::InvalidEncoding));
+        assert_that(&decode_team("", &species()[..]).err())
+            .is_equal_to(Some(TeamCodeError::Truncated));
+        assert_that(&decode_team(truncated, &species()[..]).is_err()).is_true();
+    }
+
+    #[test]
+    fn reports_unknown_species() {
+        assert_that(&decode_team(TEAM_CODE, &Vec::new()[..]).err())
+            .is_equal_to(Some(TeamCodeError::UnknownSpecies(135)));
+    }
+
+    #[test]
+    fn refuses_to_encode_monsters_without_species() {
+        let team = Team::new(vec![Monster::new(
+            MonsterType::new(Element::Normal, None),
+            Health::new(10.into()),
+        )]);
+
+        assert_that(&encode_team(&team).err())
+            .is_equal_to(Some(TeamCodeError::MissingSpecies { slot: 0 }));
+    }
+
+    #[test]
+    fn refuses_to_encode_unassigned_ids() {
+        let species = Rc::new(Species::new(
+            "Volteon",
+            MonsterType::new(Element::Electric, None),
+            Stats::new(65, 65, 60, 110, 95, 130),
+            vec![MoveDefinition::new(
+                "Thunderbolt",
+                Element::Electric,
+                AttackCategory::Special,
+                MoveDamage::Power(AttackPower::new(90.into())),
+                15,
+                Vec::new(),
+            )],
+            vec![Ability::new("Static")],
+        ));
+        let monster = |species: Rc<Species>| {
+            MonsterBuilder::new(species, Level::new(50))
+                .build()
+                .unwrap()
+        };
+        let with_species_id = Rc::new((*species).clone().with_id(SpeciesId::new(135)));
+
+        assert_that(&encode_team(&Team::new(vec![monster(species)])).err()).is_equal_to(Some(
+            TeamCodeError::UnassignedSpeciesId {
+                slot: 0,
+                species: "Volteon".to_string(),
+            },
+        ));
+        assert_that(&encode_team(&Team::new(vec![monster(with_species_id)])).err()).is_equal_to(
+            Some(TeamCodeError::UnencodableAbility {
+                slot: 0,
+                ability: "Static".to_string(),
+            }),
+        );
+    }
+
+    #[test]
+    fn refuses_to_encode_custom_tera_types() {
+        let mut team = team();
+        let custom = Element::Custom(ElementId::new(0));
+        team.members_mut()[0].set_tera_type(Some(custom));
+
+        assert_that(&encode_team(&team).err()).is_equal_to(Some(
+            TeamCodeError::UnencodableTeraType {
+                slot: 0,
+                tera_type: custom,
+            },
+        ));
+    }
+
+    #[test]
+    fn refuses_to_encode_moves_without_ids() {
+        let mut team = team();
+        team.members_mut()[0]
+            .moveset_mut()
+            .learn(MoveDefinition::new(
+                "Thunder",
+                Element::Electric,
+                AttackCategory::Special,
+                MoveDamage::Power(AttackPower::new(110.into())),
+                10,
+                Vec::new(),
+            ))
+            .unwrap();
+
+        assert_that(&encode_team(&team).err()).is_equal_to(Some(TeamCodeError::UnassignedMoveId {
+            slot: 0,
+            move_name: "Thunder".to_string(),
+        }));
+    }
+}
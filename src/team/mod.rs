@@ -0,0 +1,40 @@
+pub use code::{decode_team, encode_team, TeamCodeError};
+pub use showdown::{export_monster, export_team, import_team, ShowdownError};
+pub use validation::{TeamValidationError, TeamValidator};
+
+use crate::monster::Monster;
+
+mod code;
+mod showdown;
+mod validation;
+
+#[derive(Default)]
+pub struct Team {
+    members: Vec<Monster>,
+}
+
+impl Team {
+    pub fn new(members: Vec<Monster>) -> Self {
+        Team { members }
+    }
+
+    pub fn members(&self) -> &[Monster] {
+        &self.members
+    }
+
+    pub fn members_mut(&mut self) -> &mut [Monster] {
+        &mut self.members
+    }
+
+    pub fn add_member(&mut self, monster: Monster) {
+        self.members.push(monster);
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+}
@@ -0,0 +1,541 @@
+use std::fmt::Write;
+
+use crate::item::held_item_named;
+use crate::monster::{
+    Ability, EffortValues, Gender, IndividualValues, Level, Monster, MonsterBuilder,
+    MonsterBuilderError, Nature, Stat, StatValueError, Stats,
+};
+use crate::moves::MoveDefinition;
+use crate::species::{Species, SpeciesLookup};
+use crate::team::Team;
+use crate::Element;
+
+const MAX_MOVES: usize = 4;
+const DEFAULT_LEVEL: u8 = 100;
+const DEFAULT_HAPPINESS: u8 = 255;
+const DEFAULT_INDIVIDUAL_VALUE: u16 = 31;
+
+pub fn import_team<L: SpeciesLookup + ?Sized>(
+    text: &str,
+    species_lookup: &L,
+) -> Result<Team, ShowdownError> {
+    let mut team = Team::default();
+    let mut set: Vec<(usize, &str)> = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            if !set.is_empty() {
+                team.add_member(import_set(&set, species_lookup)?);
+                set.clear();
+            }
+        } else {
+            set.push((index + 1, line));
+        }
+    }
+    if !set.is_empty() {
+        team.add_member(import_set(&set, species_lookup)?);
+    }
+
+    Ok(team)
+}
+
+pub fn export_team(team: &Team) -> String {
+    team.members()
+        .iter()
+        .map(export_monster)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn export_monster(monster: &Monster) -> String {
+    let mut text = String::new();
+
+    match monster.nickname() {
+        Some(nickname) => write!(text, "{} ({})", nickname, monster.name()).unwrap(),
+        None => text.push_str(monster.name()),
+    }
+    match monster.gender() {
+        Some(Gender::Male) => text.push_str(" (M)"),
+        Some(Gender::Female) => text.push_str(" (F)"),
+        None => {}
+    }
+    if let Some(held_item) = monster.held_item() {
+        write!(text, " @ {}", held_item.name()).unwrap();
+    }
+    text.push('\n');
+
+    if let Some(ability) = monster.ability() {
+        writeln!(text, "Ability: {}", ability.name()).unwrap();
+    }
+    if monster.level().value() != DEFAULT_LEVEL {
+        writeln!(text, "Level: {}", monster.level().value()).unwrap();
+    }
+    if monster.is_shiny() {
+        text.push_str("Shiny: Yes\n");
+    }
+    if monster.friendship() != DEFAULT_HAPPINESS {
+        writeln!(text, "Happiness: {}", monster.friendship()).unwrap();
+    }
+    if let Some(tera_type) = monster.tera_type() {
+        writeln!(text, "Tera Type: {}", tera_type).unwrap();
+    }
+    let effort_values = stat_spread(monster.effort_values().values(), 0);
+    if !effort_values.is_empty() {
+        writeln!(text, "EVs: {}", effort_values).unwrap();
+    }
+    writeln!(text, "{} Nature", monster.nature()).unwrap();
+    let individual_values = stat_spread(
+        monster.individual_values().values(),
+        DEFAULT_INDIVIDUAL_VALUE,
+    );
+    if !individual_values.is_empty() {
+        writeln!(text, "IVs: {}", individual_values).unwrap();
+    }
+    for slot in monster.moveset().slots() {
+        writeln!(text, "- {}", slot.move_definition().name()).unwrap();
+    }
+
+    text
+}
+
+fn stat_spread(values: &Stats, omitted_value: u16) -> String {
+    Stat::ALL
+        .iter()
+        .filter(|stat| values.get(**stat) != omitted_value)
+        .map(|stat| format!("{} {}", values.get(*stat), stat_abbreviation(*stat)))
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+fn stat_abbreviation(stat: Stat) -> &'static str {
+    match stat {
+        Stat::Hp => "HP",
+        Stat::Attack => "Atk",
+        Stat::Defense => "Def",
+        Stat::SpecialAttack => "SpA",
+        Stat::SpecialDefense => "SpD",
+        Stat::Speed => "Spe",
+    }
+}
+
+fn parse_stat_spread(line: usize, spread: &str, mut values: Stats) -> Result<Stats, ShowdownError> {
+    let invalid = || ShowdownError::InvalidStatSpread {
+        line,
+        spread: spread.to_string(),
+    };
+
+    for entry in spread.split('/') {
+        let mut parts = entry.split_whitespace();
+        let (value, abbreviation) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(value), Some(abbreviation), None) => (value, abbreviation),
+            _ => return Err(invalid()),
+        };
+        let value = value.parse::<u16>().map_err(|_| invalid())?;
+        let stat = Stat::ALL
+            .iter()
+            .find(|stat| stat_abbreviation(**stat).eq_ignore_ascii_case(abbreviation))
+            .ok_or_else(invalid)?;
+        values.set(*stat, value);
+    }
+
+    Ok(values)
+}
+
+struct Header<'a> {
+    nickname: Option<&'a str>,
+    species: &'a str,
+    gender: Option<Gender>,
+    item: Option<&'a str>,
+}
+
+fn parse_header(line: &str) -> Header<'_> {
+    let (name, item) = match line.rfind(" @ ") {
+        Some(index) => (line[..index].trim(), Some(line[index + 3..].trim())),
+        None => (line, None),
+    };
+    let (name, gender) = if let Some(name) = name.strip_suffix(" (M)") {
+        (name, Some(Gender::Male))
+    } else if let Some(name) = name.strip_suffix(" (F)") {
+        (name, Some(Gender::Female))
+    } else {
+        (name, None)
+    };
+
+    match (name.rfind(" ("), name.ends_with(')')) {
+        (Some(index), true) => Header {
+            nickname: Some(name[..index].trim()),
+            species: name[index + 2..name.len() - 1].trim(),
+            gender,
+            item,
+        },
+        _ => Header {
+            nickname: None,
+            species: name,
+            gender,
+            item,
+        },
+    }
+}
+
+fn find_move(species: &Species, name: &str) -> Option<MoveDefinition> {
+    species
+        .learnable_moves()
+        .iter()
+        .chain(
+            species
+                .level_up_moves()
+                .iter()
+                .map(|(_, move_definition)| move_definition),
+        )
+        .find(|move_definition| move_definition.name().eq_ignore_ascii_case(name))
+        .cloned()
+}
+
+fn import_set<L: SpeciesLookup + ?Sized>(
+    lines: &[(usize, &str)],
+    species_lookup: &L,
+) -> Result<Monster, ShowdownError> {
+    let header_line = lines[0].0;
+    let header = parse_header(lines[0].1);
+    let species = species_lookup
+        .species_named(header.species)
+        .ok_or_else(|| ShowdownError::UnknownSpecies {
+            line: header_line,
+            species: header.species.to_string(),
+        })?;
+
+    let mut level = Level::new(DEFAULT_LEVEL);
+    let mut happiness = DEFAULT_HAPPINESS;
+    let mut shiny = false;
+    let mut tera_type = None;
+    let mut ability = None;
+    let mut nature = Nature::default();
+    let mut effort_values = EffortValues::default();
+    let mut individual_values = IndividualValues::perfect();
+    let mut moves = Vec::new();
+
+    for (line, text) in lines[1..].iter().copied() {
+        if let Some(move_name) = text.strip_prefix('-') {
+            let move_name = move_name.trim();
+            if moves.len() == MAX_MOVES {
+                return Err(ShowdownError::TooManyMoves { line });
+            }
+            moves.push(find_move(&species, move_name).ok_or_else(|| {
+                ShowdownError::UnknownMove {
+                    line,
+                    move_name: move_name.to_string(),
+                }
+            })?);
+        } else if let Some(name) = text.strip_prefix("Ability:") {
+            ability = Some(Ability::new(name.trim()));
+        } else if let Some(value) = text.strip_prefix("Level:") {
+            level = value
+                .trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|value| (1..=Level::MAX.value()).contains(value))
+                .map(Level::new)
+                .ok_or_else(|| ShowdownError::InvalidLevel {
+                    line,
+                    level: value.trim().to_string(),
+                })?;
+        } else if let Some(value) = text.strip_prefix("Happiness:") {
+            happiness =
+                value
+                    .trim()
+                    .parse::<u8>()
+                    .map_err(|_| ShowdownError::InvalidHappiness {
+                        line,
+                        happiness: value.trim().to_string(),
+                    })?;
+        } else if let Some(value) = text.strip_prefix("Shiny:") {
+            shiny = match value.trim() {
+                value if value.eq_ignore_ascii_case("yes") => true,
+                value if value.eq_ignore_ascii_case("no") => false,
+                _ => {
+                    return Err(ShowdownError::UnknownAttribute {
+                        line,
+                        attribute: text.to_string(),
+                    })
+                }
+            };
+        } else if let Some(value) = text.strip_prefix("Tera Type:") {
+            tera_type = Some(value.trim().parse::<Element>().map_err(|_| {
+                ShowdownError::UnknownTeraType {
+                    line,
+                    tera_type: value.trim().to_string(),
+                }
+            })?);
+        } else if let Some(spread) = text.strip_prefix("EVs:") {
+            let values = parse_stat_spread(line, spread.trim(), Stats::uniform(0))?;
+            effort_values = EffortValues::new(values)
+                .map_err(|source| ShowdownError::InvalidStatValues { line, source })?;
+        } else if let Some(spread) = text.strip_prefix("IVs:") {
+            let values = parse_stat_spread(
+                line,
+                spread.trim(),
+                Stats::uniform(DEFAULT_INDIVIDUAL_VALUE),
+            )?;
+            individual_values = IndividualValues::new(values)
+                .map_err(|source| ShowdownError::InvalidStatValues { line, source })?;
+        } else if let Some(name) = text.strip_suffix(" Nature") {
+            nature = Nature::named(name).ok_or_else(|| ShowdownError::UnknownNature {
+                line,
+                nature: name.trim().to_string(),
+            })?;
+        } else {
+            return Err(ShowdownError::UnknownAttribute {
+                line,
+                attribute: text.to_string(),
+            });
+        }
+    }
+
+    let mut builder = MonsterBuilder::new(species, level)
+        .without_defaults()
+        .friendship(happiness)
+        .individual_values(individual_values)
+        .effort_values(effort_values)
+        .nature(nature)
+        .moves(moves);
+    if let Some(ability) = ability {
+        builder = builder.ability(ability);
+    }
+    if let Some(item_name) = header.item {
+        let held_item = held_item_named(item_name).ok_or_else(|| ShowdownError::UnknownItem {
+            line: header_line,
+            item: item_name.to_string(),
+        })?;
+        builder = builder.held_item(held_item);
+    }
+
+    let mut monster = builder
+        .build()
+        .map_err(|source| ShowdownError::InvalidMonster {
+            line: header_line,
+            source,
+        })?;
+    monster.set_nickname(header.nickname);
+    monster.set_gender(header.gender);
+    monster.set_shiny(shiny);
+    monster.set_tera_type(tera_type);
+    Ok(monster)
+}
+
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum ShowdownError {
+    #[error("Line {line}: unknown species {species}")]
+    UnknownSpecies { line: usize, species: String },
+    #[error("Line {line}: unknown item {item}")]
+    UnknownItem { line: usize, item: String },
+    #[error("Line {line}: unknown move {move_name}")]
+    UnknownMove { line: usize, move_name: String },
+    #[error("Line {line}: unknown nature {nature}")]
+    UnknownNature { line: usize, nature: String },
+    #[error("Line {line}: invalid level {level}")]
+    InvalidLevel { line: usize, level: String },
+    #[error("Line {line}: invalid happiness {happiness}")]
+    InvalidHappiness { line: usize, happiness: String },
+    #[error("Line {line}: unknown tera type {tera_type}")]
+    UnknownTeraType { line: usize, tera_type: String },
+    #[error("Line {line}: invalid stat spread {spread}")]
+    InvalidStatSpread { line: usize, spread: String },
+    #[error("Line {line}: {source}")]
+    InvalidStatValues { line: usize, source: StatValueError },
+    #[error("Line {line}: unknown attribute {attribute}")]
+    UnknownAttribute { line: usize, attribute: String },
+    #[error("Line {line}: a monster cannot know more than four moves")]
+    TooManyMoves { line: usize },
+    #[error("Line {line}: {source}")]
+    InvalidMonster {
+        line: usize,
+        source: MonsterBuilderError,
+    },
+}
+
+impl ShowdownError {
+    pub fn line(&self) -> usize {
+        match self {
+            ShowdownError::UnknownSpecies { line, .. }
+            | ShowdownError::UnknownItem { line, .. }
+            | ShowdownError::UnknownMove { line, .. }
+            | ShowdownError::UnknownNature { line, .. }
+            | ShowdownError::InvalidLevel { line, .. }
+            | ShowdownError::InvalidHappiness { line, .. }
+            | ShowdownError::UnknownTeraType { line, .. }
+            | ShowdownError::InvalidStatSpread { line, .. }
+            | ShowdownError::InvalidStatValues { line, .. }
+            | ShowdownError::UnknownAttribute { line, .. }
+            | ShowdownError::TooManyMoves { line }
+            | ShowdownError::InvalidMonster { line, .. } => *line,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use spectral::prelude::*;
+
+    use crate::monster::{AttackCategory, AttackPower, MonsterType};
+    use crate::moves::MoveDamage;
+    use crate::Element;
+
+    use super::*;
+
+    const TEAM: &str = "\
+Sparky (Volteon) @ Choice Specs
+Ability: Static
+Level: 50
+EVs: 4 HP / 252 SpA / 252 Spe
+Timid Nature
+IVs: 0 Atk
+- Thunderbolt
+- Quick Attack
+
+Volteon @ Leftovers
+Ability: Volt Absorb
+Happiness: 70
+Modest Nature
+- Thunderbolt
+";
+
+    fn move_definition(name: &str, element: Element) -> MoveDefinition {
+        MoveDefinition::new(
+            name,
+            element,
+            AttackCategory::Special,
+            MoveDamage::Power(AttackPower::new(90.into())),
+            15,
+            Vec::new(),
+        )
+    }
+
+    fn species() -> Vec<Rc<Species>> {
+        vec![Rc::new(Species::new(
+            "Volteon",
+            MonsterType::new(Element::Electric, None),
+            Stats::new(65, 65, 60, 110, 95, 130),
+            vec![
+                move_definition("Thunderbolt", Element::Electric),
+                move_definition("Quick Attack", Element::Normal),
+            ],
+            vec![Ability::new("Static"), Ability::new("Volt Absorb")],
+        ))]
+    }
+
+    #[test]
+    fn imports_every_set() {
+        let team = import_team(TEAM, &species()[..]).unwrap();
+        let sparky = &team.members()[0];
+
+        assert_that(&team.len()).is_equal_to(2);
+        assert_that(&sparky.nickname()).is_equal_to(Some("Sparky"));
+        assert_that(&sparky.name()).is_equal_to("Volteon");
+        assert_that(&sparky.held_item().map(|item| item.name().to_string()))
+            .is_equal_to(Some("Choice Specs".to_string()));
+        assert_that(&sparky.level()).is_equal_to(Level::new(50));
+        assert_that(&sparky.nature()).is_equal_to(Nature::Timid);
+        assert_that(&sparky.effort_values().get(Stat::SpecialAttack)).is_equal_to(252);
+        assert_that(&sparky.individual_values().get(Stat::Attack)).is_equal_to(0);
+        assert_that(&sparky.individual_values().get(Stat::Speed)).is_equal_to(31);
+        assert_that(&sparky.friendship()).is_equal_to(255);
+        assert_that(&sparky.moveset().slots().len()).is_equal_to(2);
+        assert_that(&team.members()[1].friendship()).is_equal_to(70);
+    }
+
+    #[test]
+    fn round_trips_to_identical_text() {
+        let team = import_team(TEAM, &species()[..]).unwrap();
+
+        assert_that(&export_team(&team)).is_equal_to(TEAM.to_string());
+    }
+
+    #[test]
+    fn keeps_gender_markers() {
+        let team = import_team("Volteon (F)\n- Thunderbolt\n", &species()[..]).unwrap();
+
+        assert_that(&team.members()[0].nickname()).is_none();
+        assert_that(&team.members()[0].gender()).is_equal_to(Some(Gender::Female));
+    }
+
+    #[test]
+    fn round_trips_sets_without_ability_or_moves() {
+        let text = "Sparky (Volteon) (M) @ Leftovers\nShiny: Yes\nTera Type: Water\nCalm Nature\n";
+
+        let team = import_team(text, &species()[..]).unwrap();
+
+        assert_that(&team.members()[0].ability()).is_none();
+        assert_that(&team.members()[0].moveset().is_empty()).is_true();
+        assert_that(&team.members()[0].is_shiny()).is_true();
+        assert_that(&team.members()[0].tera_type()).is_equal_to(Some(Element::Water));
+        assert_that(&export_team(&team)).is_equal_to(text.to_string());
+    }
+
+    #[test]
+    fn reports_unknown_species_with_line_number() {
+        let result = import_team(
+            TEAM.replace("Volteon @", "Missingno @").as_str(),
+            &species()[..],
+        );
+
+        assert_that(&result.err()).is_equal_to(Some(ShowdownError::UnknownSpecies {
+            line: 10,
+            species: "Missingno".to_string(),
+        }));
+    }
+
+    #[test]
+    fn reports_unknown_moves_and_items() {
+        let unknown_move = import_team("Volteon\n- Surf\n", &species()[..]);
+        let unknown_item = import_team("Volteon @ Rare Candy\n", &species()[..]);
+
+        assert_that(&unknown_move.err().map(|error| error.line())).is_equal_to(Some(2));
+        assert_that(&unknown_item.err()).is_equal_to(Some(ShowdownError::UnknownItem {
+            line: 1,
+            item: "Rare Candy".to_string(),
+        }));
+    }
+
+    #[test]
+    fn reports_invalid_attributes() {
+        let invalid_spread = import_team("Volteon\nEVs: 252 Attack\n", &species()[..]);
+        let excessive_spread = import_team("Volteon\n\nVolteon\nIVs: 32 Spe\n", &species()[..]);
+        let unknown = import_team("Volteon\nGigantamax: Yes\n", &species()[..]);
+        let tera_type = import_team("Volteon\nTera Type: Stellar\n", &species()[..]);
+        let level = import_team("Volteon\nLevel: 101\n", &species()[..]);
+
+        assert_that(&invalid_spread.err().map(|error| error.line())).is_equal_to(Some(2));
+        assert_that(&excessive_spread.err().map(|error| error.line())).is_equal_to(Some(4));
+        assert_that(&unknown.err()).is_equal_to(Some(ShowdownError::UnknownAttribute {
+            line: 2,
+            attribute: "Gigantamax: Yes".to_string(),
+        }));
+        assert_that(&tera_type.err()).is_equal_to(Some(ShowdownError::UnknownTeraType {
+            line: 2,
+            tera_type: "Stellar".to_string(),
+        }));
+        assert_that(&level.err().map(|error| error.line())).is_equal_to(Some(2));
+    }
+
+    #[test]
+    fn rejects_more_than_four_moves() {
+        let text =
+            "Volteon\n- Thunderbolt\n- Thunderbolt\n- Thunderbolt\n- Thunderbolt\n- Quick Attack\n";
+
+        assert_that(&import_team(text, &species()[..]).err())
+            .is_equal_to(Some(ShowdownError::TooManyMoves { line: 6 }));
+    }
+
+    #[test]
+    fn reports_illegal_abilities_on_the_header_line() {
+        let result = import_team("Volteon\nAbility: Levitate\n", &species()[..]);
+
+        assert_that(&result.err()).is_equal_to(Some(ShowdownError::InvalidMonster {
+            line: 1,
+            source: MonsterBuilderError::AbilityNotAvailable("Levitate".to_string()),
+        }));
+    }
+}
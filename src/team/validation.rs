@@ -0,0 +1,267 @@
+use crate::monster::{Level, Monster};
+use crate::ruleset::{Ruleset, RulesetViolation};
+use crate::team::Team;
+
+pub struct TeamValidator {
+    ruleset: Ruleset,
+}
+
+impl TeamValidator {
+    pub fn new(ruleset: Ruleset) -> Self {
+        TeamValidator { ruleset }
+    }
+
+    pub fn ruleset(&self) -> &Ruleset {
+        &self.ruleset
+    }
+
+    pub fn validate(&self, team: &Team) -> Result<(), Vec<TeamValidationError>> {
+        let mut errors = Vec::new();
+
+        for (slot, monster) in team.members().iter().enumerate() {
+            Self::validate_member(slot, monster, &mut errors);
+        }
+        if let Err(violations) = self.ruleset.validate_team(team.members()) {
+            errors.extend(violations.into_iter().map(TeamValidationError::Ruleset));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validates the team and, if it is legal, applies the ruleset's level scaling so it is
+    /// ready for battle.
+    pub fn prepare(&self, mut team: Team) -> Result<Team, Vec<TeamValidationError>> {
+        self.validate(&team)?;
+        for monster in team.members_mut() {
+            self.ruleset.apply_level_scaling(monster);
+        }
+        Ok(team)
+    }
+
+    fn validate_member(slot: usize, monster: &Monster, errors: &mut Vec<TeamValidationError>) {
+        let monster_name = monster.name().to_string();
+        let species = match monster.species() {
+            Some(species) => species,
+            None => {
+                errors.push(TeamValidationError::MissingSpecies { slot });
+                return;
+            }
+        };
+
+        if monster.level() < Level::default() || monster.level() > Level::MAX {
+            errors.push(TeamValidationError::LevelOutOfRange {
+                slot,
+                monster: monster_name.clone(),
+                level: monster.level().value(),
+            });
+        }
+        match monster.ability() {
+            Some(ability) if !species.has_ability(ability) => {
+                errors.push(TeamValidationError::IllegalAbility {
+                    slot,
+                    monster: monster_name.clone(),
+                    ability: ability.name().to_string(),
+                })
+            }
+            _ => {}
+        }
+        if monster.moveset().slots().is_empty() {
+            errors.push(TeamValidationError::NoMoves {
+                slot,
+                monster: monster_name.clone(),
+            });
+        }
+        for move_slot in monster.moveset().slots() {
+            if !species.can_learn(move_slot.move_definition()) {
+                errors.push(TeamValidationError::IllegalMove {
+                    slot,
+                    monster: monster_name.clone(),
+                    move_name: move_slot.move_definition().name().to_string(),
+                });
+            }
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, thiserror::Error)]
+pub enum TeamValidationError {
+    #[error("Team member {slot} has no species")]
+    MissingSpecies { slot: usize },
+    #[error("{monster} (slot {slot}) has level {level}, outside the allowed range")]
+    LevelOutOfRange {
+        slot: usize,
+        monster: String,
+        level: u8,
+    },
+    #[error("{monster} (slot {slot}) cannot have the ability {ability}")]
+    IllegalAbility {
+        slot: usize,
+        monster: String,
+        ability: String,
+    },
+    #[error("{monster} (slot {slot}) knows no moves")]
+    NoMoves { slot: usize, monster: String },
+    #[error("{monster} (slot {slot}) cannot learn the move {move_name}")]
+    IllegalMove {
+        slot: usize,
+        monster: String,
+        move_name: String,
+    },
+    #[error(transparent)]
+    Ruleset(RulesetViolation),
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use spectral::prelude::*;
+
+    use crate::item::Leftovers;
+    use crate::monster::{Ability, AttackCategory, Health, MonsterBuilder, MonsterType, Stats};
+    use crate::moves::{MoveDamage, MoveDefinition};
+    use crate::species::Species;
+    use crate::Element;
+
+    use super::*;
+
+    fn move_definition(name: &str) -> MoveDefinition {
+        MoveDefinition::new(
+            name,
+            Element::Water,
+            AttackCategory::Special,
+            MoveDamage::None,
+            10,
+            Vec::new(),
+        )
+    }
+
+    fn monster(name: &str) -> Monster {
+        let species = Rc::new(Species::new(
+            name,
+            MonsterType::new(Element::Water, None),
+            Stats::uniform(80),
+            vec![move_definition("Surf")],
+            vec![Ability::new("Torrent")],
+        ));
+        MonsterBuilder::new(species, Level::new(50))
+            .moves(vec![move_definition("Surf")])
+            .build()
+            .unwrap()
+    }
+
+    fn under_test() -> TeamValidator {
+        TeamValidator::new(Ruleset::singles_six_versus_six())
+    }
+
+    #[test]
+    fn accepts_legal_team() {
+        assert_that(&under_test().validate(&Team::new(vec![monster("Alpha"), monster("Beta")])))
+            .is_ok();
+    }
+
+    #[test]
+    fn prepares_legal_team_by_scaling_levels() {
+        let validator = TeamValidator::new(Ruleset::level_fifty_doubles());
+        let mut high_level = monster("Alpha");
+        high_level.set_level(Level::new(80));
+        let team = Team::new(vec![
+            high_level,
+            monster("Beta"),
+            monster("Gamma"),
+            monster("Delta"),
+        ]);
+
+        let prepared = validator.prepare(team).unwrap();
+
+        assert_that(&prepared.members()[0].level()).is_equal_to(Level::new(50));
+    }
+
+    #[test]
+    fn refuses_to_prepare_illegal_team() {
+        let team = Team::new(vec![monster("Alpha"), monster("Alpha")]);
+
+        assert_that(&under_test().prepare(team).is_err()).is_true();
+    }
+
+    #[test]
+    fn reports_every_problem_in_the_team() {
+        let mut illegal_moves = monster("Alpha");
+        illegal_moves
+            .moveset_mut()
+            .learn(move_definition("Hydro Cannon"))
+            .unwrap();
+        let mut over_levelled = monster("Beta");
+        over_levelled.set_level(Level::new(101));
+        let typeless = Monster::new(MonsterType::typeless(), Health::new(10.into()));
+
+        let errors = under_test()
+            .validate(&Team::new(vec![
+                illegal_moves,
+                over_levelled,
+                typeless,
+                monster("Alpha"),
+            ]))
+            .unwrap_err();
+
+        assert_that(&errors).is_equal_to(vec![
+            TeamValidationError::IllegalMove {
+                slot: 0,
+                monster: "Alpha".to_string(),
+                move_name: "Hydro Cannon".to_string(),
+            },
+            TeamValidationError::LevelOutOfRange {
+                slot: 1,
+                monster: "Beta".to_string(),
+                level: 101,
+            },
+            TeamValidationError::MissingSpecies { slot: 2 },
+            TeamValidationError::Ruleset(RulesetViolation::DuplicateSpecies("Alpha".to_string())),
+        ]);
+    }
+
+    #[test]
+    fn reports_monster_without_moves() {
+        let mut moveless = monster("Alpha");
+        moveless.moveset_mut().forget(0).unwrap();
+
+        assert_that(&under_test().validate(&Team::new(vec![moveless]))).is_equal_to(Err(vec![
+            TeamValidationError::NoMoves {
+                slot: 0,
+                monster: "Alpha".to_string(),
+            },
+        ]));
+    }
+
+    #[test]
+    fn includes_ruleset_violations() {
+        let mut holder = monster("Alpha");
+        holder.give_held_item(Box::new(Leftovers::new()));
+        let validator =
+            TeamValidator::new(Ruleset::singles_six_versus_six().with_banned_item("Leftovers"));
+
+        assert_that(&validator.validate(&Team::new(vec![holder]))).is_equal_to(Err(vec![
+            TeamValidationError::Ruleset(RulesetViolation::BannedItem {
+                monster: "Alpha".to_string(),
+                item: "Leftovers".to_string(),
+            }),
+        ]));
+    }
+
+    #[test]
+    fn describes_errors_with_context() {
+        assert_that(
+            &TeamValidationError::IllegalMove {
+                slot: 0,
+                monster: "Alpha".to_string(),
+                move_name: "Hydro Cannon".to_string(),
+            }
+            .to_string(),
+        )
+        .is_equal_to("Alpha (slot 0) cannot learn the move Hydro Cannon".to_string());
+    }
+}